@@ -0,0 +1,163 @@
+use aoc_core::{Params, ParseError, Solution, lines, param_value, unknown_param};
+
+pub const INPUT: &str = "input";
+pub const TEST_INPUT: &str = "test_input";
+
+/// Parameters of the dial.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Day01Params {
+    /// Number of positions, numbered from 0.
+    pub dial_size: i32,
+    pub start: i32,
+}
+
+impl Default for Day01Params {
+    fn default() -> Self {
+        Day01Params {
+            dial_size: 100,
+            start: 50,
+        }
+    }
+}
+
+impl Params for Day01Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParseError> {
+        match name {
+            "dial_size" => self.dial_size = param_value(name, value)?,
+            "start" => self.start = param_value(name, value)?,
+            _ => return Err(unknown_param(name)),
+        }
+        Ok(())
+    }
+}
+
+/// The rotations of the attached document, with the dial they apply to.
+pub struct Document {
+    pub rotations: Vec<(char, i32)>,
+    pub params: Day01Params,
+}
+
+/// Position of the dial before wrapping around, in `i64` as a turn may pass `i32::MAX`.
+fn turn(direction: char, turns: i32, lock_position: i32) -> i64 {
+    match direction {
+        'L' => i64::from(lock_position) - i64::from(turns),
+        'R' => i64::from(lock_position) + i64::from(turns),
+        _ => unreachable!(),
+    }
+}
+
+/// Wraps a position of `turn` around the dial.
+fn wrap(lock_val: i64, dial_size: i32) -> i32 {
+    // below `dial_size`, so it fits
+    lock_val.rem_euclid(i64::from(dial_size)) as i32
+}
+
+/// Turns the dial, counting the rotations ending at 0.
+pub fn process_lock_part1(
+    direction: char,
+    turns: i32,
+    lock_position: i32,
+    count: u64,
+    dial_size: i32,
+) -> (i32, u64) {
+    let new_sum = wrap(turn(direction, turns, lock_position), dial_size);
+
+    (new_sum, count + u64::from(new_sum == 0))
+}
+
+/// Turns the dial, counting every click landing on 0.
+pub fn process_lock_part2(
+    direction: char,
+    turns: i32,
+    lock_position: i32,
+    count: u64,
+    dial_size: i32,
+) -> (i32, u64) {
+    let lock_val = turn(direction, turns, lock_position);
+    let size = i64::from(dial_size);
+
+    let updated_count = match lock_val {
+        ..0 => {
+            let new_count = count + lock_val.unsigned_abs() / size as u64;
+            if lock_position != 0 {
+                new_count + 1
+            } else {
+                new_count
+            }
+        }
+        // turning by 0 clicks doesn't land on 0 again
+        0 => count + u64::from(turns > 0),
+        _ if lock_val < size => count,
+        // turns are never negative, so only turning right gets here
+        _ => count + (lock_val / size) as u64,
+    };
+
+    let lock_position = wrap(lock_val, dial_size);
+
+    (lock_position, updated_count)
+}
+
+fn solution<F: Fn(char, i32, i32, u64, i32) -> (i32, u64)>(
+    document: &Document,
+    process_lock: F,
+) -> u64 {
+    let Day01Params { dial_size, start } = document.params;
+    let (_, count) = document.rotations.iter().fold(
+        (start, 0u64),
+        |(lock_position, count), &(direction, turns)| {
+            process_lock(direction, turns, lock_position, count, dial_size)
+        },
+    );
+
+    count
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Document;
+    type Params = Day01Params;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Day01Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Day01Params) -> Result<Self::Input, ParseError> {
+        if params.dial_size <= 0 || !(0..params.dial_size).contains(&params.start) {
+            let message = format!(
+                "the dial can't start at {} with {} positions",
+                params.start, params.dial_size
+            );
+            return Err(ParseError::new(message));
+        }
+
+        let rotations = lines(input)
+            .map(|line| {
+                let direction = match line.text.chars().next() {
+                    Some(direction @ ('L' | 'R')) => direction,
+                    _ => return Err(line.error(line.text, "expected a rotation like `L68`")),
+                };
+                let turns = line.parse::<i32>(&line.text[1..])?;
+                if turns < 0 {
+                    return Err(line.error(&line.text[1..], "turns can't be negative"));
+                }
+                Ok((direction, turns))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Document {
+            rotations,
+            params: *params,
+        })
+    }
+
+    fn part1(document: &Self::Input) -> u64 {
+        solution(document, process_lock_part1)
+    }
+
+    fn part2(document: &Self::Input) -> u64 {
+        solution(document, process_lock_part2)
+    }
+}
@@ -0,0 +1,86 @@
+use aoc_core::Solution;
+use proptest::prelude::*;
+use y2025_day_01::{Day01, Day01Params, TEST_INPUT};
+
+#[test]
+fn test_part_1() {
+    assert_eq!(3, Day01::part1(&Day01::parse_file(TEST_INPUT).unwrap()));
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(6, Day01::part2(&Day01::parse_file(TEST_INPUT).unwrap()));
+}
+
+#[test]
+fn test_smaller_dial() {
+    let params = Day01Params {
+        dial_size: 10,
+        start: 5,
+    };
+    let document = Day01::parse_with("L5\nR10\n", &params).unwrap();
+    assert_eq!(2, Day01::part1(&document));
+    assert_eq!(2, Day01::part2(&document));
+    assert!(
+        Day01::parse_with(
+            "L5\n",
+            &Day01Params {
+                start: 10,
+                ..params
+            }
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_negative_turns() {
+    assert!(Day01::parse("L-5\n").is_err());
+}
+
+#[test]
+fn test_largest_turns() {
+    let document = Day01::parse("R2147483647\nL2147483647\n").unwrap();
+    // 50 + 2147483647 ends at 97, turning back ends at 50
+    assert_eq!(0, Day01::part1(&document));
+    assert_eq!(2 * 21474836, Day01::part2(&document));
+}
+
+/// Turns the dial one click at a time, counting the rotations ending at 0 and the clicks
+/// landing on 0.
+fn simulate(rotations: &[(char, i32)], params: Day01Params) -> (u64, u64) {
+    let step = |direction| if direction == 'L' { -1 } else { 1 };
+    let (mut position, mut ends, mut clicks) = (params.start, 0, 0);
+    for &(direction, turns) in rotations {
+        for _ in 0..turns {
+            position = (position + step(direction)).rem_euclid(params.dial_size);
+            clicks += u64::from(position == 0);
+        }
+        ends += u64::from(position == 0);
+    }
+    (ends, clicks)
+}
+
+fn dial_and_rotations() -> impl Strategy<Value = (Day01Params, Vec<(char, i32)>)> {
+    (1..20i32).prop_flat_map(|dial_size| {
+        let rotation = (prop::sample::select(vec!['L', 'R']), 0..4 * dial_size);
+        (
+            (0..dial_size).prop_map(move |start| Day01Params { dial_size, start }),
+            prop::collection::vec(rotation, 0..20),
+        )
+    })
+}
+
+proptest! {
+    #[test]
+    fn test_against_simulation((params, rotations) in dial_and_rotations()) {
+        let input: String = rotations
+            .iter()
+            .map(|(direction, turns)| format!("{direction}{turns}\n"))
+            .collect();
+        let document = Day01::parse_with(&input, &params).unwrap();
+        let (ends, clicks) = simulate(&rotations, params);
+        prop_assert_eq!(ends, Day01::part1(&document));
+        prop_assert_eq!(clicks, Day01::part2(&document));
+    }
+}
@@ -0,0 +1,81 @@
+use aoc_core::{Answer, ParseError, Solution, lines};
+
+pub const INPUT: &str = "input";
+pub const TEST_INPUT: &str = "test_input";
+
+/// Whether the ID is a sequence of digits repeated twice.
+pub fn check_invalid_id_part1(id: u64) -> bool {
+    let id_str = id.to_string();
+    if id_str.len() % 2 == 1 {
+        return false;
+    }
+    let midpoint = id_str.len() / 2;
+
+    id_str[0..midpoint] == id_str[midpoint..]
+}
+
+/// Whether the ID is a sequence of digits repeated at least twice.
+pub fn check_invalid_id_part2(id: u64) -> bool {
+    let id = id.to_string();
+    'substr_len: for test_len in 1..(id.len() / 2 + 1) {
+        if !id.len().is_multiple_of(test_len) {
+            continue;
+        }
+
+        let occurences = id.len() / test_len;
+        for count in 1..occurences {
+            let offset = count * test_len;
+            if id[offset..offset + test_len] != id[..test_len] {
+                continue 'substr_len;
+            }
+        }
+
+        return true;
+    }
+    false
+}
+
+/// Sum of the invalid IDs of every range, `None` if it overflows.
+fn solution<F: Fn(u64) -> bool>(id_ranges: &[(u64, u64)], invalid_id_checker: F) -> Option<u64> {
+    id_ranges
+        .iter()
+        .flat_map(|&(start, end)| start..=end)
+        .filter(|&id| invalid_id_checker(id))
+        .try_fold(0u64, u64::checked_add)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    /// ID ranges in order, an ID of overlapping ranges counts once per range.
+    type Input = Vec<(u64, u64)>;
+    type Params = ();
+    type Output1 = Answer<u64>;
+    type Output2 = Answer<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut id_ranges = Vec::new();
+        for line in lines(input) {
+            let ranges = line.text.split(',').map(str::trim);
+            for range in ranges.filter(|range| !range.is_empty()) {
+                let Some((start, end)) = range.split_once('-') else {
+                    return Err(line.error(range, "expected an ID range like `11-22`"));
+                };
+                let (start, end) = (line.parse::<u64>(start)?, line.parse::<u64>(end)?);
+                if end < start {
+                    return Err(line.error(range, "the range ends before it starts"));
+                }
+                id_ranges.push((start, end));
+            }
+        }
+        Ok(id_ranges)
+    }
+
+    fn part1(id_ranges: &Self::Input) -> Answer<u64> {
+        solution(id_ranges, check_invalid_id_part1).into()
+    }
+
+    fn part2(id_ranges: &Self::Input) -> Answer<u64> {
+        solution(id_ranges, check_invalid_id_part2).into()
+    }
+}
@@ -0,0 +1,53 @@
+use aoc_core::{Answer, Solution};
+use y2025_day_02::{Day02, TEST_INPUT};
+
+/// The example of the puzzle, `test_input` ends with a malformed range after it.
+const EXAMPLE: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124\n";
+
+#[test]
+fn test_part_1() {
+    assert_eq!(
+        Answer(Some(1227775554)),
+        Day02::part1(&Day02::parse(EXAMPLE).unwrap())
+    );
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(
+        Answer(Some(4174379265)),
+        Day02::part2(&Day02::parse(EXAMPLE).unwrap())
+    );
+}
+
+#[test]
+fn test_malformed_range() {
+    let err = Day02::parse_file(TEST_INPUT).unwrap_err();
+    let location = err.location.unwrap();
+    assert_eq!((1, "asdf"), (location.line, location.token.as_str()));
+    // after the comma ending the example
+    assert_eq!(EXAMPLE.trim_end().len() + 2, location.column);
+}
+
+#[test]
+fn test_reversed_range() {
+    assert!(Day02::parse("22-11\n").is_err());
+}
+
+#[test]
+fn test_overlapping_ranges() {
+    // 11 and 22 are counted once per range
+    assert_eq!(
+        Answer(Some(66)),
+        Day02::part1(&Day02::parse("10-22,11-22\n").unwrap())
+    );
+}
+
+#[test]
+fn test_overflow() {
+    // 10101010101010101010 is over half of `u64::MAX`
+    let ids = Day02::parse(
+        "10101010101010101010-10101010101010101010,10101010101010101010-10101010101010101011\n",
+    );
+    assert_eq!(Answer(None), Day02::part2(&ids.unwrap()));
+}
@@ -0,0 +1,126 @@
+use aoc_core::{Answer, Params, ParseError, Solution, lines, param_value, unknown_param};
+
+pub const INPUT: &str = "input";
+pub const TEST_INPUT: &str = "test_input";
+
+/// Parameters of part 2, the 2 batteries of part 1 are what the puzzle is built around.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Day03Params {
+    /// Batteries turned on in each bank.
+    pub batteries: usize,
+}
+
+impl Default for Day03Params {
+    fn default() -> Self {
+        Day03Params { batteries: 12 }
+    }
+}
+
+impl Params for Day03Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParseError> {
+        match name {
+            "batteries" => self.batteries = param_value(name, value)?,
+            _ => return Err(unknown_param(name)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Banks {
+    pub banks: Vec<String>,
+    pub params: Day03Params,
+}
+
+/// Largest joltage of 2 batteries of the bank, kept in order.
+pub fn max_joltage_twopasses_part1(bank: &str) -> u64 {
+    let (start_pos, first_digit) = bank[..bank.len() - 1]
+        .char_indices()
+        .filter_map(|(pos, c)| c.to_digit(10).map(|digit| (pos, digit)))
+        .max_by_key(|&(pos, digit)| (digit, std::cmp::Reverse(pos)))
+        .unwrap_or((0, 0));
+
+    let second_digit = bank[start_pos + 1..]
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .max()
+        .unwrap_or(0);
+
+    (first_digit * 10 + second_digit) as u64
+}
+
+/// Largest joltage of `num_batteries` batteries of the bank, kept in order.
+pub fn max_joltage_part2(bank: &str, num_batteries: usize) -> u64 {
+    let digits: Vec<u32> = bank.chars().filter_map(|c| c.to_digit(10)).collect();
+
+    let mut start = 0;
+
+    (0..num_batteries).rev().fold(0u64, |joltage, end_pad| {
+        let (idx, &digit) = digits[start..digits.len() - end_pad]
+            .iter()
+            .enumerate()
+            .max_by_key(|&(i, &d)| (d, std::cmp::Reverse(i)))
+            .unwrap();
+
+        start += idx + 1;
+        joltage + u64::from(digit) * 10u64.pow(end_pad as u32)
+    })
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Banks;
+    type Params = Day03Params;
+    type Output1 = u64;
+    type Output2 = Answer<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Day03Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Day03Params) -> Result<Self::Input, ParseError> {
+        // a joltage of more than 19 digits overflows
+        if !(1..=19).contains(&params.batteries) {
+            let message = format!("can't turn on {} batteries per bank", params.batteries);
+            return Err(ParseError::new(message));
+        }
+
+        // part 1 turns on 2 batteries
+        let min_len = params.batteries.max(2);
+        let banks = lines(input)
+            .map(|line| {
+                if line.text.len() < min_len {
+                    let message = format!("a bank needs at least {min_len} batteries");
+                    return Err(line.error(line.text, message));
+                }
+                line.check_chars(|c| c.is_ascii_digit(), "battery joltage must be a digit")?;
+                Ok(line.text.to_string())
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Banks {
+            banks,
+            params: *params,
+        })
+    }
+
+    fn part1(banks: &Self::Input) -> u64 {
+        // at most 99 per bank
+        banks
+            .banks
+            .iter()
+            .map(|bank| max_joltage_twopasses_part1(bank))
+            .sum()
+    }
+
+    fn part2(banks: &Self::Input) -> Answer<u64> {
+        // joltages of up to 19 digits overflow as soon as 2 are added up
+        let num_batteries = banks.params.batteries;
+        banks
+            .banks
+            .iter()
+            .map(|bank| max_joltage_part2(bank, num_batteries))
+            .try_fold(0u64, u64::checked_add)
+            .into()
+    }
+}
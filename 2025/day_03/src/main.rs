@@ -1,83 +1,20 @@
-#[allow(dead_code)]
-const INPUT: &str = "input";
-#[allow(dead_code)]
-const TEST_INPUT: &str = "test_input";
-
-fn max_joltage_twopasses_part1(bank: &str) -> u64 {
-    let (start_pos, first_digit) = bank[..bank.len() - 1]
-        .char_indices()
-        .filter_map(|(pos, c)| c.to_digit(10).map(|digit| (pos, digit)))
-        .max_by_key(|&(pos, digit)| (digit, std::cmp::Reverse(pos)))
-        .unwrap_or((0, 0));
-
-    let second_digit = bank[start_pos + 1..]
-        .chars()
-        .filter_map(|c| c.to_digit(10))
-        .max()
-        .unwrap_or(0);
-
-    (first_digit * 10 + second_digit) as u64
-}
-
-fn max_joltage_part2(bank: &str) -> u64 {
-    const NUM_BATTERIES: usize = 12;
-
-    let digits: Vec<u32> = bank
-        .chars()
-        .filter_map(|c| c.to_digit(10))
-        .collect();
-
-    let mut start = 0;
-
-    (0..NUM_BATTERIES)
-        .rev()
-        .fold(0u64, |joltage, end_pad| {
-            let (idx, &digit) = digits[start..digits.len() - end_pad]
-                .iter()
-                .enumerate()
-                .max_by_key(|&(i, &d)| (d, std::cmp::Reverse(i)))
-                .unwrap();
-
-            start += idx + 1;
-            joltage + u64::from(digit) * 10u64.pow(end_pad as u32)
-        })
-}
-
-fn solution<F: Fn(&str) -> u64>(file_path: &str, max_joltage: F) -> u64 {
-    std::fs::read_to_string(file_path)
-        .expect("Cannot open file")
-        .lines()
-        .map(max_joltage)
-        .sum()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        assert_eq!(357, solution(TEST_INPUT, max_joltage_twopasses_part1));
-        assert_eq!(17278, solution(INPUT, max_joltage_twopasses_part1));
-    }
-
-    #[test]
-    fn test_part_2() {
-        assert_eq!(3121910778619, solution(TEST_INPUT, max_joltage_part2));
-        assert_eq!(171528556468625, solution(INPUT, max_joltage_part2));
-    }
-}
+use aoc_core::Solution;
+use y2025_day_03::{Day03, TEST_INPUT};
 
 fn main() {
     let file_path = TEST_INPUT;
-    // let file_path = INPUT;
+    // let file_path = y2025_day_03::INPUT;
+    let input = Day03::parse_file(file_path).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        std::process::exit(1)
+    });
 
     println!(
         "The solution part 1 for \"{file_path}\" is {}",
-        solution(file_path, max_joltage_twopasses_part1)
+        Day03::part1(&input)
     );
     println!(
         "The solution part 2 for \"{file_path}\" is {}",
-        solution(file_path, max_joltage_part2)
+        Day03::part2(&input)
     );
 }
@@ -0,0 +1,62 @@
+use aoc_core::{Answer, Solution};
+use proptest::prelude::*;
+use y2025_day_03::{Day03, Day03Params, TEST_INPUT};
+
+#[test]
+fn test_part_1() {
+    assert_eq!(357, Day03::part1(&Day03::parse_file(TEST_INPUT).unwrap()));
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(
+        Answer(Some(3121910778619)),
+        Day03::part2(&Day03::parse_file(TEST_INPUT).unwrap())
+    );
+}
+
+#[test]
+fn test_batteries() {
+    // part 2 with the 2 batteries of part 1
+    let params = Day03Params { batteries: 2 };
+    let banks = Day03::parse_file_with(TEST_INPUT, &params).unwrap();
+    assert_eq!(Answer(Some(357)), Day03::part2(&banks));
+    assert!(Day03::parse_with("123\n", &Day03Params { batteries: 4 }).is_err());
+
+    let params = Day03Params { batteries: 19 };
+    let banks = Day03::parse_with("9999999999999999999\n9999999999999999999\n", &params);
+    assert_eq!(Answer(None), Day03::part2(&banks.unwrap()));
+}
+
+/// Largest joltage over every choice of `num_batteries` batteries of the bank.
+fn brute_force(bank: &str, num_batteries: usize) -> u64 {
+    let digits: Vec<u64> = bank.bytes().map(|b| u64::from(b - b'0')).collect();
+    (0u32..1 << digits.len())
+        .filter(|mask| mask.count_ones() as usize == num_batteries)
+        .map(|mask| {
+            digits
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| mask & 1 << i != 0)
+                .fold(0, |joltage, (_, &digit)| joltage * 10 + digit)
+        })
+        .max()
+        .unwrap()
+}
+
+fn bank_and_batteries() -> impl Strategy<Value = (String, usize)> {
+    "[0-9]{2,12}".prop_flat_map(|bank| {
+        let len = bank.len();
+        (Just(bank), 1..=len)
+    })
+}
+
+proptest! {
+    #[test]
+    fn test_against_brute_force((bank, batteries) in bank_and_batteries()) {
+        let params = Day03Params { batteries };
+        let banks = Day03::parse_with(&bank, &params).unwrap();
+        prop_assert_eq!(brute_force(&bank, 2), Day03::part1(&banks));
+        prop_assert_eq!(Answer(Some(brute_force(&bank, batteries))), Day03::part2(&banks));
+    }
+}
@@ -0,0 +1,122 @@
+use aoc_core::{
+    Border, Grid, Params, ParseError, Solution, box_filter, param_value, unknown_param,
+};
+
+pub const INPUT: &str = "input";
+pub const TEST_INPUT: &str = "test_input";
+
+/// Parameters of the forklifts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Day04Params {
+    /// Rolls with fewer neighbors than this can be accessed by a forklift.
+    pub threshold: u16,
+    /// Bound on the removal rounds of part 2.
+    pub max_iters: usize,
+}
+
+impl Default for Day04Params {
+    fn default() -> Self {
+        Day04Params {
+            threshold: 4,
+            max_iters: 1000,
+        }
+    }
+}
+
+impl Params for Day04Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParseError> {
+        match name {
+            "threshold" => self.threshold = param_value(name, value)?,
+            "max_iters" => self.max_iters = param_value(name, value)?,
+            _ => return Err(unknown_param(name)),
+        }
+        Ok(())
+    }
+}
+
+/// Grid of the rolls, 1 for a roll and 0 for an empty cell.
+pub struct Rolls {
+    pub grid: Grid<u16>,
+    pub params: Day04Params,
+}
+
+/// Removes the rolls with fewer than `threshold` neighbors, returns how many were removed.
+pub fn count_neighbor_rolls(rolls: &mut Grid<u16>, threshold: u16) -> u64 {
+    let mut neighbor_counts = box_filter(rolls, 1, Border::Zero);
+
+    let mut freed_rolls = 0u64;
+    for (roll_row, neighbors_row) in rolls.rows_mut().zip(neighbor_counts.rows_mut()) {
+        for (roll, neighbor_count) in roll_row.iter_mut().zip(neighbors_row.iter_mut()) {
+            if *roll == 1 {
+                *neighbor_count -= 1;
+                if *neighbor_count < threshold {
+                    freed_rolls += 1;
+                    // `rolls` mutation is the only difference from part 1
+                    *roll = 0;
+                }
+            }
+        }
+    }
+    freed_rolls
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Rolls;
+    type Params = Day04Params;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Day04Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Day04Params) -> Result<Self::Input, ParseError> {
+        let rolls = Grid::parse(
+            input,
+            |c| match c {
+                '.' => Some(0),
+                '@' => Some(1),
+                _ => None,
+            },
+            "expected `.` or `@`",
+        )?;
+
+        Ok(Rolls {
+            grid: rolls,
+            params: *params,
+        })
+    }
+
+    fn part1(rolls: &Self::Input) -> u64 {
+        let threshold = rolls.params.threshold;
+        let rolls = &rolls.grid;
+        let box_acc = box_filter(rolls, 1, Border::Zero);
+
+        // the roll itself is in its box
+        rolls
+            .iter()
+            .filter(|&(pos, &roll)| roll == 1 && box_acc[pos] - 1 < threshold)
+            .count() as u64
+    }
+
+    fn part2(rolls: &Self::Input) -> u64 {
+        let Day04Params {
+            threshold,
+            max_iters,
+        } = rolls.params;
+        let mut rolls = rolls.grid.clone();
+
+        let mut extractable_rolls = 0u64;
+        for _ in 0..max_iters {
+            let freed_rolls = count_neighbor_rolls(&mut rolls, threshold);
+            extractable_rolls += freed_rolls;
+
+            if freed_rolls == 0 {
+                return extractable_rolls;
+            }
+        }
+        extractable_rolls
+    }
+}
@@ -0,0 +1,26 @@
+use aoc_core::{Solution, read_input};
+use y2025_day_04::{Day04, TEST_INPUT, count_neighbor_rolls};
+
+#[test]
+fn part_1() {
+    assert_eq!(13, Day04::part1(&Day04::parse_file(TEST_INPUT).unwrap()));
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(43, Day04::part2(&Day04::parse_file(TEST_INPUT).unwrap()));
+}
+
+#[test]
+fn test_display() {
+    let input = read_input(TEST_INPUT).unwrap();
+    let mut rolls = Day04::parse(&input).unwrap().grid;
+    let roll = |&cell: &u16| if cell == 1 { '@' } else { '.' };
+    assert_eq!(input, rolls.display(roll).to_string());
+
+    // the first round removes the rolls of part 1
+    let before = input.matches('@').count();
+    assert_eq!(13, count_neighbor_rolls(&mut rolls, 4));
+    let after = rolls.display(roll).to_string();
+    assert_eq!(before - 13, after.matches('@').count());
+}
@@ -0,0 +1,62 @@
+use aoc_core::{IntervalSet, ParseError, Solution, lines};
+
+pub const INPUT: &str = "input";
+pub const TEST_INPUT: &str = "test_input";
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    /// Fresh IDs and the available IDs.
+    type Input = (IntervalSet<u64>, Vec<u64>);
+    type Params = ();
+    type Output1 = u64;
+    type Output2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = lines(input);
+        let missing = || ParseError::new("expected ID ranges, a blank line and the available IDs");
+
+        // ranges and IDs are separated by a blank line
+        let mut fresh = IntervalSet::new();
+        let mut separated = false;
+        for line in lines.by_ref() {
+            if line.text.is_empty() {
+                separated = true;
+                break;
+            }
+            let Some((start, end)) = line.text.split_once('-') else {
+                let message = "expected an ID range like `3-5`, or a blank line before the IDs";
+                return Err(line.error(line.text, message));
+            };
+            let (start, end) = (line.parse::<u64>(start)?, line.parse::<u64>(end)?);
+            if end < start {
+                return Err(line.error(line.text, "the range ends before it starts"));
+            }
+            fresh.insert(start..=end);
+        }
+        if !separated || fresh.is_empty() {
+            return Err(missing());
+        }
+
+        let ids: Vec<u64> = lines
+            .map(|line| line.parse::<u64>(line.text))
+            .collect::<Result<_, _>>()?;
+        if ids.is_empty() {
+            return Err(missing());
+        }
+
+        Ok((fresh, ids))
+    }
+
+    fn part1((fresh, ids): &Self::Input) -> u64 {
+        ids.iter().filter(|&&id| fresh.contains(id)).count() as u64
+    }
+
+    /// Counted in `u128`, every `u64` is one more than it holds.
+    fn part2((fresh, _): &Self::Input) -> u128 {
+        fresh
+            .ranges()
+            .map(|range| u128::from(range.end() - range.start()) + 1)
+            .sum()
+    }
+}
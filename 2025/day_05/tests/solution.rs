@@ -0,0 +1,39 @@
+use aoc_core::Solution;
+use y2025_day_05::{Day05, TEST_INPUT};
+
+#[test]
+fn test_merging_intervals() {
+    let (fresh, _) = Day05::parse("3-5\n10-14\n16-20\n12-18\n\n1\n").unwrap();
+    assert_eq!(vec![3..=5, 10..=20], fresh.ranges().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_part_1() {
+    assert_eq!(3, Day05::part1(&Day05::parse_file(TEST_INPUT).unwrap()));
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(14, Day05::part2(&Day05::parse_file(TEST_INPUT).unwrap()));
+}
+
+#[test]
+fn test_every_id() {
+    let (fresh, ids) = Day05::parse("0-18446744073709551615\n\n7\n").unwrap();
+    assert_eq!(1, Day05::part1(&(fresh.clone(), ids)));
+    assert_eq!(1 << 64, Day05::part2(&(fresh, vec![])));
+}
+
+#[test]
+fn test_missing_sections() {
+    for input in ["3-5\n", "3-5\n\n", "\n1\n", "3-5\n1\n", ""] {
+        assert!(Day05::parse(input).is_err(), "{input:?}");
+    }
+    let err = Day05::parse("3-5\n1\n").unwrap_err().to_string();
+    assert!(err.contains("blank line"), "{err}");
+}
+
+#[test]
+fn test_reversed_range() {
+    assert!(Day05::parse("5-3\n\n4\n").is_err());
+}
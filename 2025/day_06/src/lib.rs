@@ -0,0 +1,127 @@
+use aoc_core::{Answer, Line, ParseError, Solution, lines};
+
+pub const INPUT: &str = "input";
+pub const TEST_INPUT: &str = "test_input";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Op {
+    Add,
+    Mul,
+}
+
+impl Op {
+    /// Result of the problem, `None` if it overflows.
+    pub fn apply(self, numbers: &[u64]) -> Option<u64> {
+        match self {
+            Op::Add => numbers.iter().try_fold(0u64, |acc, &x| acc.checked_add(x)),
+            Op::Mul => numbers.iter().try_fold(1u64, |acc, &x| acc.checked_mul(x)),
+        }
+    }
+}
+
+/// A problem of the worksheet, with its numbers read both ways.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Problem {
+    /// Numbers read in rows, top to bottom.
+    pub rows: Vec<u64>,
+    /// Numbers read in columns, the digits top to bottom, from left to right.
+    pub columns: Vec<u64>,
+    pub op: Op,
+}
+
+/// Grand total of the problems with their numbers picked by `numbers`, `None` if it
+/// overflows.
+fn grand_total(problems: &[Problem], numbers: impl Fn(&Problem) -> &[u64]) -> Option<u64> {
+    problems.iter().try_fold(0u64, |total, problem| {
+        total.checked_add(problem.op.apply(numbers(problem))?)
+    })
+}
+
+/// Splits the worksheet into problems at the columns blank in every row.
+fn parse_problems(number_lines: &[Line], op_line: &Line) -> Result<Vec<Problem>, ParseError> {
+    let width = op_line.text.len();
+    let blank = |col: usize| {
+        op_line.text.as_bytes()[col] == b' '
+            && number_lines
+                .iter()
+                .all(|line| line.text.as_bytes()[col] == b' ')
+    };
+
+    let mut problems = Vec::new();
+    let mut start = 0;
+    while start < width {
+        if blank(start) {
+            start += 1;
+            continue;
+        }
+        let end = (start..width).find(|&col| blank(col)).unwrap_or(width);
+
+        let op = match op_line.text[start..end].trim() {
+            "+" => Op::Add,
+            "*" => Op::Mul,
+            _ => {
+                let message = "expected one operator per problem";
+                return Err(op_line.error(&op_line.text[start..end], message));
+            }
+        };
+        let rows = number_lines
+            .iter()
+            .map(|line| line.parse::<u64>(line.text[start..end].trim()))
+            .collect::<Result<_, _>>()?;
+        let columns = (start..end)
+            .map(|col| {
+                let digits: String = number_lines
+                    .iter()
+                    .map(|line| char::from(line.text.as_bytes()[col]))
+                    .filter(|&c| c != ' ')
+                    .collect();
+                digits.parse::<u64>().map_err(|err| {
+                    let message = format!("invalid number `{digits}` in column {}: {err}", col + 1);
+                    op_line.error(&op_line.text[col..=col], message)
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        problems.push(Problem { rows, columns, op });
+        start = end;
+    }
+    Ok(problems)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Problem>;
+    type Params = ();
+    type Output1 = Answer<u64>;
+    type Output2 = Answer<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<Line> = lines(input).collect();
+        let Some((op_line, number_lines)) = lines.split_last().filter(|(_, rows)| !rows.is_empty())
+        else {
+            return Err(ParseError::new(
+                "expected rows of numbers followed by an operator row",
+            ));
+        };
+
+        op_line.check_chars(|c| matches!(c, '+' | '*' | ' '), "expected `+` or `*`")?;
+        let width = op_line.text.len();
+        for line in number_lines {
+            line.check_chars(|c| c.is_ascii_digit() || c == ' ', "expected a digit")?;
+            if line.text.len() != width {
+                return Err(line.error(line.text, format!("expected a row of {width} characters")));
+            }
+        }
+
+        parse_problems(number_lines, op_line)
+    }
+
+    fn part1(problems: &Self::Input) -> Answer<u64> {
+        grand_total(problems, |problem| &problem.rows).into()
+    }
+
+    fn part2(problems: &Self::Input) -> Answer<u64> {
+        grand_total(problems, |problem| &problem.columns).into()
+    }
+}
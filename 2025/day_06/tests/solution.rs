@@ -0,0 +1,58 @@
+use aoc_core::{Answer, Solution};
+use y2025_day_06::{Day06, Op, Problem, TEST_INPUT};
+
+#[test]
+fn test_part_1() {
+    assert_eq!(
+        Answer(Some(4277556)),
+        Day06::part1(&Day06::parse_file(TEST_INPUT).unwrap())
+    );
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(
+        Answer(Some(3263827)),
+        Day06::part2(&Day06::parse_file(TEST_INPUT).unwrap())
+    );
+}
+
+#[test]
+fn test_parse() {
+    let problems = Day06::parse("12 3\n 4 5\n+  *\n").unwrap();
+    assert_eq!(
+        vec![
+            Problem {
+                rows: vec![12, 4],
+                columns: vec![1, 24],
+                op: Op::Add,
+            },
+            Problem {
+                rows: vec![3, 5],
+                columns: vec![35],
+                op: Op::Mul,
+            },
+        ],
+        problems
+    );
+    // a problem needs one operator and digits in every column
+    assert!(Day06::parse("12\n34\n++\n").is_err());
+    assert!(Day06::parse("1  2\n3  4\n*  +\n").is_ok());
+    assert!(Day06::parse("1 2\n3  \n*  \n").is_err());
+}
+
+#[test]
+fn test_blank_lines() {
+    // the trailing blank line isn't an operator row
+    let worksheet = Day06::parse("12\r\n3 \r\n* \n\n").unwrap();
+    assert_eq!(Answer(Some(12 * 3)), Day06::part1(&worksheet));
+    assert_eq!(Answer(Some(13 * 2)), Day06::part2(&worksheet));
+}
+
+#[test]
+fn test_overflow() {
+    let worksheet = Day06::parse("99999999999\n99999999999\n*          \n").unwrap();
+    assert_eq!(Answer(None), Day06::part1(&worksheet));
+    assert_eq!(Answer(None), Day06::part2(&worksheet));
+    assert_eq!("none", Day06::part2(&worksheet).to_string());
+}
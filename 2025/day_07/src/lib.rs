@@ -0,0 +1,117 @@
+use aoc_core::{Answer, Grid, ParseError, Solution, lines};
+use bit_vec::BitVec;
+
+pub const INPUT: &str = "input";
+pub const TEST_INPUT: &str = "test_input";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cell {
+    Empty,
+    Start,
+    Splitter,
+}
+
+fn splitters(row: &[Cell]) -> impl Iterator<Item = usize> {
+    row.iter()
+        .enumerate()
+        .filter(|&(_, &cell)| cell == Cell::Splitter)
+        .map(|(x, _)| x)
+}
+
+pub struct Manifold {
+    /// Column of the starting `S`.
+    pub start_idx: usize,
+    /// The start in the first row, splitters in the rows below.
+    pub grid: Grid<Cell>,
+}
+
+/// Timelines of a particle taking both ways at every splitter, `None` if they overflow.
+pub fn count_timelines(manifold: &Manifold) -> Option<u64> {
+    let mut timelines = vec![0u64; manifold.grid.width()];
+    timelines[manifold.start_idx] = 1;
+
+    for row in manifold.grid.rows().skip(1) {
+        for splitter in splitters(row) {
+            let num_timelines = std::mem::take(&mut timelines[splitter]);
+            if num_timelines == 0 {
+                continue;
+            }
+            // the parser rejects splitters on the edges
+            for side in [splitter - 1, splitter + 1] {
+                timelines[side] = timelines[side].checked_add(num_timelines)?;
+            }
+        }
+    }
+
+    timelines.into_iter().try_fold(0u64, u64::checked_add)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Manifold;
+    type Params = ();
+    type Output1 = u64;
+    type Output2 = Answer<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid::parse(
+            input,
+            |c| match c {
+                '.' => Some(Cell::Empty),
+                'S' => Some(Cell::Start),
+                '^' => Some(Cell::Splitter),
+                _ => None,
+            },
+            "expected `.`, `S` or `^`",
+        )?;
+
+        // every cell is a single byte
+        let last = grid.width() - 1;
+        for (line, row) in lines(input).zip(grid.rows()) {
+            let (misplaced, message) = if line.number == 1 {
+                (Cell::Splitter, "expected `.` or `S`")
+            } else {
+                (Cell::Start, "expected `.` or `^`")
+            };
+            if let Some(x) = row.iter().position(|&cell| cell == misplaced) {
+                return Err(line.error(&line.text[x..=x], message));
+            }
+            // beams are split to both sides, a splitter on the edge would send one outside
+            if let Some(x) = [0, last].into_iter().find(|&x| row[x] == Cell::Splitter) {
+                return Err(line.error(&line.text[x..=x], "splitter on the edge"));
+            }
+        }
+        let start_idx = grid.row(0).iter().position(|&cell| cell == Cell::Start);
+        let Some(start_idx) = start_idx else {
+            let first_line = lines(input).next().expect("The grid isn't empty");
+            return Err(first_line.error(first_line.text, "missing the starting `S`"));
+        };
+
+        Ok(Manifold { start_idx, grid })
+    }
+
+    fn part1(manifold: &Self::Input) -> u64 {
+        let linelength = manifold.grid.width();
+        let mut beams = BitVec::from_elem(linelength, false);
+        beams.set(manifold.start_idx, true);
+
+        let mut split_counter: u64 = 0;
+        for row in manifold.grid.rows().skip(1) {
+            for splitter in splitters(row) {
+                if beams[splitter] {
+                    split_counter += 1;
+                    beams.set(splitter, false);
+                    beams.set(std::cmp::max(0, splitter - 1), true);
+                    beams.set(std::cmp::min(splitter + 1, linelength - 1), true);
+                }
+            }
+        }
+
+        split_counter
+    }
+
+    fn part2(manifold: &Self::Input) -> Answer<u64> {
+        count_timelines(manifold).into()
+    }
+}
@@ -0,0 +1,37 @@
+use aoc_core::{Answer, Solution, read_input};
+use y2025_day_07::{Cell, Day07, TEST_INPUT};
+
+#[test]
+fn test_part_1() {
+    assert_eq!(21, Day07::part1(&Day07::parse_file(TEST_INPUT).unwrap()));
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(
+        Answer(Some(40)),
+        Day07::part2(&Day07::parse_file(TEST_INPUT).unwrap())
+    );
+}
+
+#[test]
+fn test_display() {
+    let input = read_input(TEST_INPUT).unwrap();
+    let manifold = Day07::parse(&input).unwrap();
+    let shown = manifold.grid.display(|cell| match cell {
+        Cell::Empty => '.',
+        Cell::Start => 'S',
+        Cell::Splitter => '^',
+    });
+    assert_eq!(input, shown.to_string());
+}
+
+#[test]
+fn test_overflow() {
+    // every row of splitters doubles the timelines
+    let mut input = String::from("..S..\n");
+    for _ in 0..70 {
+        input.push_str("..^..\n.^.^.\n");
+    }
+    assert_eq!(Answer(None), Day07::part2(&Day07::parse(&input).unwrap()));
+}
@@ -0,0 +1,275 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use aoc_core::{Params, ParseError, Solution, UnionFind, lines, param_value, unknown_param};
+
+pub const INPUT: &str = "input";
+pub const TEST_INPUT: &str = "test_input";
+
+/// Parameters of the junction boxes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Day08Params {
+    /// Closest pairs connected in part 1.
+    pub pairs: usize,
+}
+
+impl Day08Params {
+    /// The example connects 10 pairs of its 20 junction boxes.
+    pub const EXAMPLE: Self = Day08Params { pairs: 10 };
+}
+
+impl Default for Day08Params {
+    fn default() -> Self {
+        Day08Params { pairs: 1000 }
+    }
+}
+
+impl Params for Day08Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParseError> {
+        match name {
+            "pairs" => self.pairs = param_value(name, value)?,
+            _ => return Err(unknown_param(name)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+
+impl Point {
+    fn coord(&self, axis: usize) -> u32 {
+        [self.x, self.y, self.z][axis]
+    }
+}
+
+/// Squared distance, enough to order the pairs.
+pub fn dist(a: &Point, b: &Point) -> u128 {
+    (0..3)
+        .map(|axis| u128::from(a.coord(axis).abs_diff(b.coord(axis))).pow(2))
+        .sum()
+}
+
+/// Junction box positions, one `x,y,z` per line.
+pub fn parse_points(input: &str) -> Result<Vec<Point>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let coords = line
+                .text
+                .split(',')
+                .map(|coord| line.parse::<u32>(coord))
+                .collect::<Result<Vec<_>, _>>()?;
+            match coords[..] {
+                [x, y, z] => Ok(Point { x, y, z }),
+                _ => Err(line.error(line.text, "expected a position like `162,817,812`")),
+            }
+        })
+        .collect()
+}
+
+/// k-d tree of the points, splitting on x, y and z in turn.
+pub struct KdTree {
+    /// Point indices, the median of every subtree in the middle of its slice with the
+    /// points below it on the left and the others on the right.
+    nodes: Vec<usize>,
+}
+
+impl KdTree {
+    pub fn new(points: &[Point]) -> Self {
+        fn build(nodes: &mut [usize], points: &[Point], depth: usize) {
+            if nodes.len() <= 1 {
+                return;
+            }
+            let (mid, axis) = (nodes.len() / 2, depth % 3);
+            nodes.select_nth_unstable_by_key(mid, |&idx| points[idx].coord(axis));
+            let (left, right) = nodes.split_at_mut(mid);
+            build(left, points, depth + 1);
+            build(&mut right[1..], points, depth + 1);
+        }
+
+        let mut nodes: Vec<usize> = (0..points.len()).collect();
+        build(&mut nodes, points, 0);
+        KdTree { nodes }
+    }
+
+    /// Up to `k` points after `target` in the input, closest first and by index on a tie.
+    pub fn nearest_after(&self, points: &[Point], target: usize, k: usize) -> Vec<(u128, usize)> {
+        let mut nearest = BinaryHeap::with_capacity(k + 1);
+        if k > 0 {
+            self.search(&self.nodes, points, target, 0, k, &mut nearest);
+        }
+        nearest.into_sorted_vec()
+    }
+
+    /// Keeps the `k` closest points of the subtree in `nearest`, the farthest on top.
+    fn search(
+        &self,
+        nodes: &[usize],
+        points: &[Point],
+        target: usize,
+        depth: usize,
+        k: usize,
+        nearest: &mut BinaryHeap<(u128, usize)>,
+    ) {
+        if nodes.is_empty() {
+            return;
+        }
+        let mid = nodes.len() / 2;
+        let idx = nodes[mid];
+        if idx > target {
+            nearest.push((dist(&points[target], &points[idx]), idx));
+            if nearest.len() > k {
+                nearest.pop();
+            }
+        }
+
+        let axis = depth % 3;
+        let (coord, split) = (points[target].coord(axis), points[idx].coord(axis));
+        let (near, far) = if coord < split {
+            (&nodes[..mid], &nodes[mid + 1..])
+        } else {
+            (&nodes[mid + 1..], &nodes[..mid])
+        };
+        self.search(near, points, target, depth + 1, k, nearest);
+        // the far side is at least as far as the splitting plane, ties may still be closer
+        // by index
+        let plane = u128::from(coord.abs_diff(split)).pow(2);
+        if nearest.len() < k
+            || nearest
+                .peek()
+                .is_some_and(|&(farthest, _)| plane <= farthest)
+        {
+            self.search(far, points, target, depth + 1, k, nearest);
+        }
+    }
+}
+
+/// Index pairs of all points, closest first and in index order on a tie, found as they
+/// are needed.
+pub struct ClosestPairs<'a> {
+    points: &'a [Point],
+    tree: &'a KdTree,
+    /// Neighbors after every point not paired yet, the closest last, and how many were
+    /// found.
+    neighbors: Vec<(Vec<(u128, usize)>, usize)>,
+    /// The closest remaining pair of every point with a point after it.
+    heads: BinaryHeap<Reverse<(u128, usize, usize)>>,
+}
+
+impl<'a> ClosestPairs<'a> {
+    /// Neighbors looked up at first for every point, doubled whenever they run out.
+    const NEIGHBORS: usize = 8;
+
+    pub fn new(points: &'a [Point], tree: &'a KdTree) -> Self {
+        let mut pairs = ClosestPairs {
+            points,
+            tree,
+            neighbors: vec![(Vec::new(), 0); points.len()],
+            heads: BinaryHeap::with_capacity(points.len()),
+        };
+        for idx in 0..points.len() {
+            pairs.push_next(idx);
+        }
+        pairs
+    }
+
+    /// Queues the next pair of `idx`, looking up more neighbors if they ran out.
+    fn push_next(&mut self, idx: usize) {
+        let (next, found) = &mut self.neighbors[idx];
+        let after = self.points.len() - 1 - idx;
+        if next.is_empty() && *found < after {
+            let wanted = (2 * *found).max(Self::NEIGHBORS).min(after);
+            let nearest = self.tree.nearest_after(self.points, idx, wanted);
+            // the closest ones were already paired
+            *next = nearest[*found..].iter().rev().copied().collect();
+            *found = nearest.len();
+        }
+        if let Some((distance, other)) = next.pop() {
+            self.heads.push(Reverse((distance, idx, other)));
+        }
+    }
+}
+
+impl Iterator for ClosestPairs<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        let Reverse((_, idx, other)) = self.heads.pop()?;
+        self.push_next(idx);
+        Some((idx, other))
+    }
+}
+
+pub struct Playground {
+    pub points: Vec<Point>,
+    pub tree: KdTree,
+    pub n_closest_pairs: usize,
+}
+
+impl Playground {
+    /// Point index pairs, closest first.
+    pub fn pairs(&self) -> ClosestPairs<'_> {
+        ClosestPairs::new(&self.points, &self.tree)
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Playground;
+    type Params = Day08Params;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Day08Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Day08Params) -> Result<Self::Input, ParseError> {
+        let points = parse_points(input)?;
+        let pairs = points.len() * points.len().saturating_sub(1) / 2;
+        let n_closest_pairs = params.pairs;
+
+        if n_closest_pairs == 0 {
+            return Err(ParseError::new("part 1 connects at least 1 pair"));
+        }
+        if pairs < n_closest_pairs {
+            let message = format!(
+                "{} junction boxes make {pairs} pairs, {n_closest_pairs} are needed",
+                points.len(),
+            );
+            return Err(ParseError::new(message));
+        }
+
+        let tree = KdTree::new(&points);
+        Ok(Playground {
+            points,
+            tree,
+            n_closest_pairs,
+        })
+    }
+
+    fn part1(playground: &Self::Input) -> usize {
+        let mut circuits = UnionFind::new(playground.points.len());
+        for (pt_a, pt_b) in playground.pairs().take(playground.n_closest_pairs) {
+            circuits.union(pt_a, pt_b);
+        }
+
+        circuits.largest(3).into_iter().product()
+    }
+
+    fn part2(playground: &Self::Input) -> usize {
+        let points = &playground.points;
+
+        let mut circuits = UnionFind::new(points.len());
+        for (pt_a, pt_b) in playground.pairs() {
+            if circuits.union(pt_a, pt_b) && circuits.components() == 1 {
+                return (points[pt_a].x as usize) * (points[pt_b].x as usize);
+            }
+        }
+        panic!("All clusters should have been merged");
+    }
+}
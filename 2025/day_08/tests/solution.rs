@@ -0,0 +1,53 @@
+use aoc_core::Solution;
+use proptest::prelude::*;
+use y2025_day_08::{Day08, Day08Params, TEST_INPUT, dist};
+
+fn example() -> <Day08 as Solution>::Input {
+    Day08::parse_file_with(TEST_INPUT, &Day08Params::EXAMPLE).unwrap()
+}
+
+#[test]
+fn test_part_1() {
+    assert_eq!(40, Day08::part1(&example()));
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(25272, Day08::part2(&example()));
+}
+
+#[test]
+fn test_too_few_pairs() {
+    // 20 junction boxes make 190 pairs
+    assert!(Day08::parse_file(TEST_INPUT).is_err());
+}
+
+#[test]
+fn test_distant_boxes() {
+    // squared distances past `u32::MAX` still order the pairs
+    let input = "0,0,0\n4000000000,0,0\n0,4000000001,0\n";
+    let playground = Day08::parse_with(input, &Day08Params { pairs: 1 }).unwrap();
+    assert_eq!(
+        vec![(0, 1), (0, 2), (1, 2)],
+        playground.pairs().collect::<Vec<_>>()
+    );
+}
+
+/// Every pair, closest first and in index order on a tie.
+fn sorted_pairs(input: &<Day08 as Solution>::Input) -> Vec<(usize, usize)> {
+    let points = &input.points;
+    let mut pairs: Vec<(usize, usize)> = (0..points.len())
+        .flat_map(|i| (i + 1..points.len()).map(move |j| (i, j)))
+        .collect();
+    pairs.sort_by_key(|&(i, j)| dist(&points[i], &points[j]));
+    pairs
+}
+
+proptest! {
+    #[test]
+    fn test_pairs_against_sorting(points in prop::collection::vec((0..6u32, 0..6u32, 0..6u32), 2..60)) {
+        let input: String = points.iter().map(|(x, y, z)| format!("{x},{y},{z}\n")).collect();
+        let playground = Day08::parse_with(&input, &Day08Params { pairs: 1 }).unwrap();
+        prop_assert_eq!(sorted_pairs(&playground), playground.pairs().collect::<Vec<_>>());
+    }
+}
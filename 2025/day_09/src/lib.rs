@@ -0,0 +1,196 @@
+use aoc_core::{ParseError, Solution, lines};
+use itertools::{Itertools, izip};
+
+pub const INPUT: &str = "input";
+pub const TEST_INPUT: &str = "test_input";
+
+#[derive(Debug)]
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+}
+struct Rectangle {
+    x_min: u32,
+    x_max: u32,
+    y_min: u32,
+    y_max: u32,
+}
+impl Rectangle {
+    fn new(a: &Point, b: &Point) -> Self {
+        Rectangle {
+            x_min: a.x.min(b.x),
+            x_max: a.x.max(b.x),
+            y_min: a.y.min(b.y),
+            y_max: a.y.max(b.y),
+        }
+    }
+}
+
+/// Red tiles in order, each in a row or column with the previous one.
+pub fn read_polygon(input: &str) -> Result<Vec<Point>, ParseError> {
+    let mut points: Vec<Point> = Vec::with_capacity(input.lines().count());
+    let mut last_line = None;
+    for line in lines(input) {
+        let Some((x, y)) = line.text.split_once(',') else {
+            return Err(line.error(line.text, "expected a position like `7,1`"));
+        };
+        let point = Point {
+            x: line.parse(x)?,
+            y: line.parse(y)?,
+        };
+        if let Some(previous) = points.last()
+            && !is_axis_aligned(previous, &point)
+        {
+            return Err(line.error(line.text, "not in a row or column with the previous tile"));
+        }
+        points.push(point);
+        last_line = Some(line);
+    }
+
+    // the polygon closes from the last tile back to the first one
+    if let (Some(line), [first, .., last]) = (last_line, &points[..])
+        && !is_axis_aligned(last, first)
+    {
+        return Err(line.error(line.text, "not in a row or column with the first tile"));
+    }
+
+    Ok(points)
+}
+
+fn is_axis_aligned(a: &Point, b: &Point) -> bool {
+    (a.x == b.x) != (a.y == b.y)
+}
+
+/// Whether the point lies on the axis aligned edge between two tiles.
+pub fn is_pt_on_edge(pt: &Point, edge_pt_a: &Point, edge_pt_b: &Point) -> bool {
+    let (a, b) = (edge_pt_a, edge_pt_b);
+
+    let edge_is_horizontal = a.y == b.y;
+    let edge_is_vertical = a.x == b.x;
+    std::debug_assert!(edge_is_vertical != edge_is_horizontal);
+
+    if edge_is_horizontal {
+        let (x_min, x_max) = (a.x.min(b.x), a.x.max(b.x));
+        pt.y == a.y && (x_min..=x_max).contains(&pt.x)
+    } else {
+        let (y_min, y_max) = (a.y.min(b.y), a.y.max(b.y));
+        pt.x == a.x && (y_min..=y_max).contains(&pt.y)
+    }
+}
+
+fn edge_intersects_rect(a: &Point, b: &Point, r: &Rectangle) -> bool {
+    if a.y == b.y {
+        if r.y_min < a.y && a.y < r.y_max && a.x.max(b.x) > r.x_min && r.x_max > a.x.min(b.x) {
+            return true;
+        }
+    } else {
+        #[allow(clippy::collapsible_else_if)]
+        if r.x_min < a.x && a.x < r.x_max && a.y.max(b.y) > r.y_min && r.y_max > a.y.min(b.y) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Whether the rectangle with opposite corners `pt1` and `pt2` lies inside the polygon.
+/// The polygon is taken as continuous, which matches its tiles as long as no two edges are
+/// next to each other.
+pub fn is_rectangle_valid(pt1: &Point, pt2: &Point, polygon: &[Point]) -> bool {
+    let rect = Rectangle::new(pt1, pt2);
+    let rect_points_to_check = [Point { x: pt1.x, y: pt2.y }, Point { x: pt2.x, y: pt1.y }];
+    let mut are_rect_points_inside = [false; 2];
+    let mut keep_checking_rect_points = [true; 2];
+
+    for (a, b) in polygon.iter().circular_tuple_windows() {
+        for (pt, inside, check_pt) in izip!(
+            &rect_points_to_check,
+            &mut are_rect_points_inside,
+            &mut keep_checking_rect_points
+        ) {
+            // check if rect points are inside the polygon
+            if *check_pt && is_pt_on_edge(pt, a, b) {
+                *inside = true;
+                *check_pt = false;
+            }
+
+            if *check_pt {
+                let pt_in_vertical_span = (a.y > pt.y) != (b.y > pt.y);
+                let boundary_cross_check = if pt_in_vertical_span && a.y != b.y {
+                    // the product of two differences of `u32` overflows an `i64`
+                    let (px, py, ax, ay, bx, by) = (
+                        i128::from(pt.x),
+                        i128::from(pt.y),
+                        i128::from(a.x),
+                        i128::from(a.y),
+                        i128::from(b.x),
+                        i128::from(b.y),
+                    );
+                    px < (ax - bx) * (py - ay) / (by - ay) + ax
+                } else {
+                    false
+                };
+                if boundary_cross_check {
+                    *inside = !*inside;
+                }
+            }
+        }
+        if edge_intersects_rect(a, b, &rect) {
+            return false;
+        }
+    }
+    if !are_rect_points_inside.into_iter().all(|b| b) {
+        return false;
+    }
+
+    true
+}
+
+enum PuzzlePart {
+    One,
+    Two,
+}
+
+fn solution(polygon: &[Point], puzzle_part: PuzzlePart) -> u128 {
+    polygon
+        .iter()
+        .tuple_combinations()
+        .fold(0, |old_area, (pt_a, pt_b)| {
+            // the largest area, 2^32 by 2^32 tiles, doesn't fit a `u64`
+            let width = u128::from(pt_a.x.abs_diff(pt_b.x)) + 1;
+            let heigth = u128::from(pt_a.y.abs_diff(pt_b.y)) + 1;
+            let new_area = width * heigth;
+
+            match puzzle_part {
+                PuzzlePart::One => old_area.max(new_area),
+                PuzzlePart::Two => {
+                    if new_area > old_area && is_rectangle_valid(pt_a, pt_b, polygon) {
+                        new_area
+                    } else {
+                        old_area
+                    }
+                }
+            }
+        })
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Point>;
+    type Params = ();
+    type Output1 = u128;
+    type Output2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_polygon(input)
+    }
+
+    fn part1(polygon: &Self::Input) -> u128 {
+        solution(polygon, PuzzlePart::One)
+    }
+
+    fn part2(polygon: &Self::Input) -> u128 {
+        solution(polygon, PuzzlePart::Two)
+    }
+}
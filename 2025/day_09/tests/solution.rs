@@ -0,0 +1,170 @@
+use aoc_core::Solution;
+use proptest::prelude::*;
+use y2025_day_09::{Day09, Point, TEST_INPUT, is_pt_on_edge, is_rectangle_valid};
+
+#[test]
+fn test_is_square_valid() {
+    let polygon = Day09::parse_file(TEST_INPUT).unwrap();
+
+    assert!(is_rectangle_valid(
+        &Point { x: 9, y: 5 },
+        &Point { x: 2, y: 3 },
+        &polygon
+    ));
+    assert!(is_rectangle_valid(
+        &Point { x: 7, y: 1 },
+        &Point { x: 11, y: 1 },
+        &polygon
+    ));
+}
+
+#[test]
+fn test_rectangle_with_a_corner_outside() {
+    let polygon = Day09::parse("0,7\n4,7\n4,6\n6,6\n6,0\n4,0\n4,4\n0,4\n").unwrap();
+    // (4, 4) is red but (0, 0) is outside
+    assert!(!is_rectangle_valid(
+        &Point { x: 4, y: 0 },
+        &Point { x: 0, y: 4 },
+        &polygon
+    ));
+    assert_eq!(21, Day09::part2(&polygon));
+}
+
+#[test]
+fn test_largest_coordinates() {
+    let polygon = Day09::parse("0,0\n4294967295,0\n4294967295,4294967295\n0,4294967295\n");
+    let polygon = polygon.unwrap();
+    assert_eq!(1 << 64, Day09::part1(&polygon));
+    assert_eq!(1 << 64, Day09::part2(&polygon));
+}
+
+#[test]
+fn test_part_1() {
+    assert_eq!(50, Day09::part1(&Day09::parse_file(TEST_INPUT).unwrap()));
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(24, Day09::part2(&Day09::parse_file(TEST_INPUT).unwrap()));
+}
+
+/// Every tile of the edge between two tiles in a row or column.
+fn edge_tiles(a: &Point, b: &Point) -> Vec<(u32, u32)> {
+    let xs = a.x.min(b.x)..=a.x.max(b.x);
+    xs.flat_map(|x| (a.y.min(b.y)..=a.y.max(b.y)).map(move |y| (x, y)))
+        .collect()
+}
+
+fn point() -> impl Strategy<Value = Point> {
+    (0..8u32, 0..8u32).prop_map(|(x, y)| Point { x, y })
+}
+
+fn edge() -> impl Strategy<Value = (Point, Point)> {
+    (point(), 1..8u32, any::<bool>()).prop_map(|(a, len, horizontal)| {
+        let b = if horizontal {
+            Point {
+                x: a.x + len,
+                y: a.y,
+            }
+        } else {
+            Point {
+                x: a.x,
+                y: a.y + len,
+            }
+        };
+        (a, b)
+    })
+}
+
+proptest! {
+    #[test]
+    fn test_is_pt_on_edge((a, b) in edge(), pt in point(), reversed in any::<bool>()) {
+        let on_edge = edge_tiles(&a, &b).contains(&(pt.x, pt.y));
+        let (a, b) = if reversed { (b, a) } else { (a, b) };
+        prop_assert_eq!(on_edge, is_pt_on_edge(&pt, &a, &b));
+    }
+}
+
+/// A band of columns between a top and a bottom staircase like the generated inputs,
+/// its rows and columns swapped if `transposed`.
+fn staircase() -> impl Strategy<Value = Vec<Point>> {
+    (1..6usize).prop_flat_map(|columns| {
+        // columns 1 apart leave notches without a tile in them, which the solution
+        // doesn't handle, like edges of the puzzle input never being next to each other
+        let gaps = prop::collection::vec(2..5u32, columns);
+        let steps = |range: std::ops::Range<u32>| {
+            prop::collection::vec(range, columns).prop_filter("flat step", |heights| {
+                heights.windows(2).all(|pair| pair[0] != pair[1])
+            })
+        };
+        (gaps, steps(6..11), steps(0..5), any::<bool>()).prop_map(
+            |(gaps, tops, bottoms, transposed)| {
+                let xs: Vec<u32> = std::iter::once(0)
+                    .chain(gaps.iter().scan(0, |x, gap| {
+                        *x += gap;
+                        Some(*x)
+                    }))
+                    .collect();
+                let mut tiles = Vec::new();
+                for (col, &top) in tops.iter().enumerate() {
+                    tiles.extend([(xs[col], top), (xs[col + 1], top)]);
+                }
+                for (col, &bottom) in bottoms.iter().enumerate().rev() {
+                    tiles.extend([(xs[col + 1], bottom), (xs[col], bottom)]);
+                }
+                tiles
+                    .into_iter()
+                    .map(|(x, y)| {
+                        if transposed {
+                            Point { x: y, y: x }
+                        } else {
+                            Point { x, y }
+                        }
+                    })
+                    .collect()
+            },
+        )
+    })
+}
+
+/// Largest rectangle between two red tiles with every one of its tiles red or green.
+fn brute_force_part2(polygon: &[Point]) -> u128 {
+    let edges: Vec<(&Point, &Point)> = polygon.iter().zip(polygon.iter().cycle().skip(1)).collect();
+    let is_inside = |x: u32, y: u32| {
+        let pt = Point { x, y };
+        if edges
+            .iter()
+            .any(|(a, b)| edge_tiles(a, b).contains(&(x, y)))
+        {
+            return true;
+        }
+        // a tile off the edges is inside if a ray to the right crosses an odd number of
+        // columns of the edges
+        let crossings = edges
+            .iter()
+            .filter(|(a, b)| {
+                a.x == b.x && a.x > pt.x && (a.y.min(b.y)..a.y.max(b.y)).contains(&pt.y)
+            })
+            .count();
+        crossings % 2 == 1
+    };
+
+    let mut largest = 0;
+    for (i, a) in polygon.iter().enumerate() {
+        for b in &polygon[i + 1..] {
+            let (xs, ys) = (a.x.min(b.x)..=a.x.max(b.x), a.y.min(b.y)..=a.y.max(b.y));
+            let area = (xs.clone().count() * ys.clone().count()) as u128;
+            if area > largest && xs.clone().all(|x| ys.clone().all(|y| is_inside(x, y))) {
+                largest = area;
+            }
+        }
+    }
+    largest
+}
+
+proptest! {
+    #[test]
+    fn test_part_2_against_brute_force(polygon in staircase()) {
+        prop_assert_eq!(brute_force_part2(&polygon), Day09::part2(&polygon));
+    }
+}
@@ -0,0 +1,243 @@
+use aoc_core::{Answer, Line, ParseError, Solution, lines};
+use binarray::BinaryArray;
+use rayon::prelude::*;
+use std::collections::{HashSet, VecDeque};
+use z3::{
+    Optimize, SatResult,
+    ast::{Bool, Int},
+};
+
+pub const INPUT: &str = "input";
+pub const TEST_INPUT: &str = "test_input";
+
+pub struct Machine {
+    /// Lights to turn on, as bits.
+    pub target: u16,
+    /// Lights toggled by each button, as bits.
+    pub buttons: Vec<u16>,
+    pub joltage: Vec<usize>,
+}
+
+impl Machine {
+    fn convert_buttons(&self) -> Vec<Vec<usize>> {
+        self.buttons
+            .iter()
+            .map(|button| button.to_indices())
+            .collect()
+    }
+
+    /// Constraints on the presses of every button to reach the joltages.
+    fn joltage_constraints(&self, presses: &[Int]) -> Vec<Bool> {
+        let buttons = self.convert_buttons();
+        let mut constraints: Vec<Bool> = presses.iter().map(|button| button.ge(0)).collect();
+
+        for (pos, &target) in self.joltage.iter().enumerate() {
+            let sum = Int::add(
+                &buttons
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, button)| button.contains(&pos))
+                    .map(|(idx, _)| &presses[idx])
+                    .collect::<Vec<&Int>>(),
+            );
+            constraints.push(sum.eq(Int::from_u64(target as u64)));
+        }
+        constraints
+    }
+
+    fn fresh_presses(&self) -> Vec<Int> {
+        (0..self.buttons.len())
+            .map(|idx| Int::fresh_const(&format!("button_{idx}")))
+            .collect()
+    }
+
+    /// Fewest presses of the buttons reaching the joltages, `None` if there are none or z3
+    /// can't tell.
+    fn fewest_presses_joltage(&self) -> Option<usize> {
+        let opt = Optimize::new();
+        let total = Int::fresh_const("total");
+
+        let presses = self.fresh_presses();
+        for constraint in self.joltage_constraints(&presses) {
+            opt.assert(&constraint);
+        }
+        opt.assert(&total.eq(Int::add(&presses)));
+        opt.minimize(&total);
+
+        match opt.check(&[]) {
+            SatResult::Sat => opt
+                .get_model()
+                .and_then(|model| model.eval(&total, true))
+                .and_then(|total| total.as_u64())
+                .and_then(|total| usize::try_from(total).ok()),
+            SatResult::Unsat | SatResult::Unknown => None,
+        }
+    }
+
+    /// Whether pressing some of the buttons turns on exactly the target lights, by
+    /// eliminating the bits of the buttons from the highest one.
+    fn can_reach_lights(&self) -> bool {
+        let mut basis: Vec<u16> = Vec::new();
+        for &button in &self.buttons {
+            let reduced = basis.iter().fold(button, |bits, &row| bits.min(bits ^ row));
+            if reduced != 0 {
+                basis.push(reduced);
+                // rows by decreasing highest bit
+                basis.sort_unstable_by(|a, b| b.cmp(a));
+            }
+        }
+        basis
+            .iter()
+            .fold(self.target, |bits, &row| bits.min(bits ^ row))
+            == 0
+    }
+
+    fn fewest_presses_lights(&self) -> usize {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        queue.push_back((0, 0));
+        while let Some((state, n)) = queue.pop_front() {
+            if visited.contains(&state) {
+                continue;
+            }
+            if state == self.target {
+                return n;
+            }
+            visited.insert(state);
+            for button in self.buttons.iter() {
+                let next = state ^ button;
+                queue.push_back((next, n + 1));
+            }
+        }
+
+        unreachable!("the parser rejects the lights the buttons can't turn on")
+    }
+}
+
+/// Lights are stored as bits of a `u16`.
+const MAX_LIGHTS: usize = 16;
+
+/// Parses the comma separated numbers of a button or the joltages, e.g. `1,3` of `(1,3)`.
+fn parse_values(line: &Line, interior: &str, num_lights: usize) -> Result<Vec<usize>, ParseError> {
+    interior
+        .split(',')
+        .map(|num| match line.parse::<usize>(num)? {
+            index if index >= num_lights => {
+                Err(line.error(num, format!("the machine has only {num_lights} lights")))
+            }
+            value => Ok(value),
+        })
+        .collect()
+}
+
+/// Machines, one per line like `[.##.] (3) (1,3) {3,5,4,7}`.
+pub fn input_generator(input: &str) -> Result<Vec<Machine>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let mut parts = line.text.split_whitespace();
+            let first = parts.next().unwrap_or(line.text);
+
+            // Machine variables
+            let mut target = 0_u16;
+            let mut buttons = Vec::new();
+            let mut joltage = None;
+
+            let Some(lights) = first
+                .strip_prefix('[')
+                .and_then(|lights| lights.strip_suffix(']'))
+            else {
+                return Err(line.error(first, "expected indicator lights like `[.##.]`"));
+            };
+            if lights.len() > MAX_LIGHTS {
+                return Err(line.error(lights, format!("more than {MAX_LIGHTS} lights")));
+            }
+            for (idx, ch) in lights.char_indices() {
+                match ch {
+                    '#' => target.set_bit(idx, true),
+                    '.' => {}
+                    _ => {
+                        return Err(
+                            line.error(&lights[idx..idx + ch.len_utf8()], "expected `.` or `#`")
+                        );
+                    }
+                }
+            }
+
+            for part in parts {
+                // Split up each capsule
+                if let Some(interior) = part
+                    .strip_prefix('(')
+                    .and_then(|part| part.strip_suffix(')'))
+                {
+                    let mut button = 0_u16;
+                    for index in parse_values(&line, interior, lights.len())? {
+                        button.set_bit(index, true);
+                    }
+                    buttons.push(button);
+                } else if let Some(interior) = part
+                    .strip_prefix('{')
+                    .and_then(|part| part.strip_suffix('}'))
+                {
+                    let values = parse_values(&line, interior, usize::MAX)?;
+                    if joltage.is_some() || values.len() != lights.len() {
+                        let message = format!(
+                            "expected one joltage requirement per light, {}",
+                            lights.len()
+                        );
+                        return Err(line.error(part, message));
+                    }
+                    joltage = Some(values);
+                } else {
+                    return Err(line.error(
+                        part,
+                        "expected a button like `(1,3)` or joltages like `{3,5}`",
+                    ));
+                }
+            }
+
+            let Some(joltage) = joltage else {
+                return Err(line.error("", "missing the joltage requirements"));
+            };
+
+            let machine = Machine {
+                target,
+                buttons,
+                joltage,
+            };
+            // cheap to check, unlike the joltages left to part 2
+            if !machine.can_reach_lights() {
+                return Err(line.error(first, "no presses of the buttons turn on these lights"));
+            }
+            Ok(machine)
+        })
+        .collect()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Machine>;
+    type Params = ();
+    type Output1 = usize;
+    type Output2 = Answer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+
+    fn part1(machines: &Self::Input) -> usize {
+        machines
+            .par_iter()
+            .map(Machine::fewest_presses_lights)
+            .sum()
+    }
+
+    fn part2(machines: &Self::Input) -> Answer<usize> {
+        // `None` as soon as a machine has no solution
+        machines
+            .par_iter()
+            .map(Machine::fewest_presses_joltage)
+            .try_reduce(|| 0, usize::checked_add)
+            .into()
+    }
+}
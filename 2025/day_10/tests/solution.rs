@@ -0,0 +1,27 @@
+use aoc_core::{Answer, Solution};
+use y2025_day_10::{Day10, TEST_INPUT};
+
+#[test]
+fn test_part_1() {
+    assert_eq!(Day10::part1(&Day10::parse_file(TEST_INPUT).unwrap()), 7);
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(
+        Day10::part2(&Day10::parse_file(TEST_INPUT).unwrap()),
+        Answer(Some(33))
+    );
+}
+
+#[test]
+fn test_unsolvable_machines() {
+    // no button turns on the light
+    assert!(Day10::parse("[#] {1}\n").is_err());
+    assert!(Day10::parse("[##] (0) (0,1) {1,1}\n").is_ok());
+    assert!(Day10::parse("[#.] (0,1) {1,1}\n").is_err());
+    // the lights can be turned on, but both joltages always rise together
+    let machines = Day10::parse("[..] (0,1) {1,2}\n").unwrap();
+    assert_eq!(Day10::part1(&machines), 0);
+    assert_eq!(Day10::part2(&machines), Answer(None));
+}
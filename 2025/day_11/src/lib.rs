@@ -0,0 +1,100 @@
+use aoc_core::{Answer, ParseError, Solution, lines};
+use pathfinding::directed::topological_sort::topological_sort;
+use std::collections::HashMap;
+
+pub const INPUT: &str = "input";
+
+/// Outputs of every device, one `aaa: bbb ccc` per line.
+pub fn build_graph(input: &str) -> Result<HashMap<String, Vec<String>>, ParseError> {
+    let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+    for line in lines(input) {
+        let Some((start, outs)) = line.text.split_once(':') else {
+            return Err(line.error(line.text, "expected a device like `aaa: you hhh`"));
+        };
+        let name = start.trim();
+        if name.is_empty() {
+            return Err(line.error(start, "missing the device name"));
+        }
+        if graph.contains_key(name) {
+            let message = format!("device `{name}` is already listed above");
+            return Err(line.error(name, message));
+        }
+        let outs: Vec<String> = outs.split_whitespace().map(String::from).collect();
+        graph.insert(name.to_string(), outs);
+    }
+
+    // paths are counted, so a loop would make them endless
+    let devices: Vec<String> = graph.keys().cloned().collect();
+    topological_sort(&devices, |device| {
+        graph.get(device).into_iter().flatten().cloned()
+    })
+    .map_err(|device| ParseError::new(format!("device `{device}` is in a loop")))?;
+
+    Ok(graph)
+}
+
+/// Paths from `start` to `end`, `None` if they overflow.
+fn count_paths(graph: &HashMap<String, Vec<String>>, start: &str, end: &str) -> Option<usize> {
+    let outputs = |device: &&str| {
+        graph
+            .get(*device)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+    };
+    let devices = topological_sort(&[start], outputs).expect("the parser rejects loops");
+
+    // the devices after `device` come first, their paths are known by then
+    let mut paths: HashMap<&str, usize> = HashMap::with_capacity(devices.len());
+    for device in devices.into_iter().rev() {
+        let count = if device == end {
+            1
+        } else {
+            outputs(&device)
+                .into_iter()
+                .try_fold(0usize, |count, next| count.checked_add(paths[next]))?
+        };
+        paths.insert(device, count);
+    }
+    Some(paths[start])
+}
+
+fn solution_part_1(graph: &HashMap<String, Vec<String>>) -> Option<usize> {
+    count_paths(graph, "you", "out")
+}
+
+/// Paths from `svr` to `out` through both `dac` and `fft`.
+fn solution_part_2(graph: &HashMap<String, Vec<String>>) -> Option<usize> {
+    // the graph has no loops, so only one of `dac` and `fft` can lead to the other
+    let (first, second) = if count_paths(graph, "dac", "fft")? != 0 {
+        ("dac", "fft")
+    } else {
+        ("fft", "dac")
+    };
+
+    count_paths(graph, "svr", first)?
+        .checked_mul(count_paths(graph, first, second)?)?
+        .checked_mul(count_paths(graph, second, "out")?)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = HashMap<String, Vec<String>>;
+    type Params = ();
+    type Output1 = Answer<usize>;
+    type Output2 = Answer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        build_graph(input)
+    }
+
+    fn part1(graph: &Self::Input) -> Answer<usize> {
+        solution_part_1(graph).into()
+    }
+
+    fn part2(graph: &Self::Input) -> Answer<usize> {
+        solution_part_2(graph).into()
+    }
+}
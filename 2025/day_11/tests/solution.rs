@@ -0,0 +1,48 @@
+use aoc_core::{Answer, Solution};
+use y2025_day_11::Day11;
+
+#[test]
+fn test_part_1() {
+    assert_eq!(
+        Day11::part1(&Day11::parse_file("test_input_1").unwrap()),
+        Answer(Some(5))
+    );
+}
+
+#[test]
+fn test_part_2() {
+    assert_eq!(
+        Day11::part2(&Day11::parse_file("test_input_2").unwrap()),
+        Answer(Some(2))
+    );
+}
+
+#[test]
+fn test_loop() {
+    assert!(Day11::parse("you: aaa\naaa: bbb out\nbbb: aaa\n").is_err());
+}
+
+#[test]
+fn test_duplicate_device() {
+    let err = Day11::parse("you: aaa\naaa: out\n aaa : bbb\n").unwrap_err();
+    let location = err.location.unwrap();
+    assert_eq!(
+        (3, 2, "aaa"),
+        (location.line, location.column, location.token.as_str())
+    );
+}
+
+#[test]
+fn test_overflow() {
+    // every device doubles the paths of the one before
+    let mut input = String::from("you: d0\n");
+    for idx in 0..70 {
+        input.push_str(&format!(
+            "d{idx}: a{idx} b{idx}\na{idx}: d{}\nb{idx}: d{}\n",
+            idx + 1,
+            idx + 1
+        ));
+    }
+    input.push_str("d70: out\n");
+    assert_eq!(Answer(None), Day11::part1(&Day11::parse(&input).unwrap()));
+}
@@ -0,0 +1,51 @@
+use std::hint::black_box;
+use std::path::Path;
+
+use aoc_core::Solution;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+const ROOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Benchmarks parsing and both parts of a day on its puzzle input, grouped by crate as
+/// `yYYYY_day_XX/parse`, `yYYYY_day_XX/part1` and `yYYYY_day_XX/part2`.
+fn bench_day<S: Solution>(c: &mut Criterion, year: u16, day: u8) {
+    let name = format!("y{year}_day_{day:02}");
+    let file = Path::new(ROOT_DIR)
+        .join(year.to_string())
+        .join(format!("day_{day:02}"))
+        .join("input");
+    let Ok(text) = std::fs::read_to_string(file) else {
+        eprintln!("Skipping {name}, missing input file");
+        return;
+    };
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&text))));
+
+    let input = S::parse(&text).unwrap_or_else(|err| panic!("{err}"));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input))));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<y2025_day_01::Day01>(c, 2025, 1);
+    bench_day::<y2025_day_02::Day02>(c, 2025, 2);
+    bench_day::<y2025_day_03::Day03>(c, 2025, 3);
+    bench_day::<y2025_day_04::Day04>(c, 2025, 4);
+    bench_day::<y2025_day_05::Day05>(c, 2025, 5);
+    bench_day::<y2025_day_06::Day06>(c, 2025, 6);
+    bench_day::<y2025_day_07::Day07>(c, 2025, 7);
+    bench_day::<y2025_day_08::Day08>(c, 2025, 8);
+    bench_day::<y2025_day_09::Day09>(c, 2025, 9);
+    bench_day::<y2025_day_10::Day10>(c, 2025, 10);
+    bench_day::<y2025_day_11::Day11>(c, 2025, 11);
+}
+
+criterion_group! {
+    name = benches;
+    // some parts take hundreds of milliseconds, keep a full run in the minutes
+    config = Criterion::default().sample_size(10);
+    targets = days
+}
+criterion_main!(benches);
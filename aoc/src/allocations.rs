@@ -0,0 +1,106 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// System allocator counting allocations, installed as the global allocator by the
+/// `alloc-stats` feature.
+#[cfg_attr(not(feature = "alloc-stats"), allow(dead_code))]
+pub struct CountingAlloc;
+
+#[cfg_attr(not(feature = "alloc-stats"), allow(dead_code))]
+impl CountingAlloc {
+    fn allocated(size: usize) {
+        COUNT.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            // a reallocation counts as a new allocation replacing the old one
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            Self::allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Whether the counting allocator is installed, otherwise nothing is counted.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// Allocations made while running a step.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Allocations {
+    pub count: usize,
+    /// Bytes allocated in total, including the freed ones.
+    pub bytes: usize,
+    /// Most bytes allocated at once, over the ones live before the step.
+    pub peak: usize,
+}
+
+/// Runs `f` and counts its allocations, and those of any other thread meanwhile.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Allocations) {
+    let (count, bytes, live) = (
+        COUNT.load(Ordering::Relaxed),
+        BYTES.load(Ordering::Relaxed),
+        LIVE.load(Ordering::Relaxed),
+    );
+    PEAK.store(live, Ordering::Relaxed);
+
+    let result = f();
+    let allocations = Allocations {
+        count: COUNT.load(Ordering::Relaxed) - count,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (result, allocations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let layout = Layout::from_size_align(1 << 20, 8).unwrap();
+        let ((), allocations) = measure(|| unsafe {
+            let ptr = CountingAlloc.alloc(layout);
+            let ptr = CountingAlloc.realloc(ptr, layout, 2 << 20);
+            CountingAlloc.dealloc(ptr, Layout::from_size_align(2 << 20, 8).unwrap());
+        });
+
+        // other tests may allocate meanwhile with the feature on
+        assert!(allocations.count >= 2);
+        assert!(allocations.bytes >= 3 << 20);
+        assert!(allocations.peak >= 2 << 20);
+    }
+}
@@ -0,0 +1,306 @@
+use std::collections::BTreeMap;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+use aoc_core::Overrides;
+use serde::{Deserialize, Deserializer};
+use toml_edit::{Array, DocumentMut, Item, Table};
+
+/// Expected answers per day and input file, as kept in `answers.toml`:
+///
+/// ```toml
+/// [day_01.input]
+/// part1 = "1018"
+/// part2 = "5815"
+/// ```
+///
+/// Answers are strings, plain TOML integers are accepted as well. `aoc submit` also keeps
+/// the answers the site rejected, in `part1_wrong` and `part2_wrong`. Inputs solved with
+/// other parameters than the puzzle input's name them, e.g. `params = { pairs = 10 }`.
+/// Inputs the parser must reject give the start of its message instead of answers, e.g.
+/// `error = "invalid value"`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerRegistry {
+    days: BTreeMap<String, BTreeMap<String, Answers>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers {
+    #[serde(default, deserialize_with = "answer")]
+    part1: Option<String>,
+    #[serde(default, deserialize_with = "answer")]
+    part2: Option<String>,
+    #[serde(default)]
+    part1_wrong: Vec<String>,
+    #[serde(default)]
+    part2_wrong: Vec<String>,
+    #[serde(default, deserialize_with = "params")]
+    params: Overrides,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+/// A TOML value kept as text.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Scalar {
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+    Text(String),
+}
+
+impl From<Scalar> for String {
+    fn from(value: Scalar) -> Self {
+        match value {
+            Scalar::Integer(value) => value.to_string(),
+            Scalar::Float(value) => value.to_string(),
+            Scalar::Bool(value) => value.to_string(),
+            Scalar::Text(value) => value,
+        }
+    }
+}
+
+fn answer<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    Ok(Some(Scalar::deserialize(deserializer)?.into()))
+}
+
+fn params<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Overrides, D::Error> {
+    let params = BTreeMap::<String, Scalar>::deserialize(deserializer)?;
+    Ok(params
+        .into_iter()
+        .map(|(name, value)| (name, value.into()))
+        .collect())
+}
+
+fn day_key(day: u8) -> String {
+    format!("day_{day:02}")
+}
+
+impl AnswerRegistry {
+    /// Loads the registry, a missing file is an empty registry but a file that can't be
+    /// read is an error, not to forget the answers known to be wrong.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|err| io::Error::new(ErrorKind::InvalidData, err))
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn expected(&self, day: u8, input: &str, part: u8) -> Option<&str> {
+        let answers = self.days.get(&day_key(day))?.get(input)?;
+        match part {
+            1 => answers.part1.as_deref(),
+            _ => answers.part2.as_deref(),
+        }
+    }
+
+    /// Start of the parse error of an input the parser must reject.
+    pub fn expected_error(&self, day: u8, input: &str) -> Option<&str> {
+        self.days.get(&day_key(day))?.get(input)?.error.as_deref()
+    }
+
+    /// Parameters the input is solved with, on top of the day's defaults.
+    pub fn params(&self, day: u8, input: &str) -> Overrides {
+        self.days
+            .get(&day_key(day))
+            .and_then(|inputs| inputs.get(input))
+            .map(|answers| answers.params.clone())
+            .unwrap_or_default()
+    }
+
+    /// Whether `answer` was already rejected by the site.
+    pub fn is_wrong(&self, day: u8, input: &str, part: u8, answer: &str) -> bool {
+        let Some(answers) = self
+            .days
+            .get(&day_key(day))
+            .and_then(|inputs| inputs.get(input))
+        else {
+            return false;
+        };
+        let wrong = match part {
+            1 => &answers.part1_wrong,
+            _ => &answers.part2_wrong,
+        };
+        wrong.iter().any(|wrong| wrong == answer)
+    }
+
+    /// Input file names with registered answers for the day.
+    pub fn inputs(&self, day: u8) -> impl Iterator<Item = &str> {
+        self.days
+            .get(&day_key(day))
+            .into_iter()
+            .flat_map(|inputs| inputs.keys().map(String::as_str))
+    }
+}
+
+/// Records the site's verdict on `answer` in the registry at `path`, keeping the rest of the
+/// file as it is. A correct answer never replaces another one already registered.
+pub fn record(
+    path: &Path,
+    day: u8,
+    input: &str,
+    part: u8,
+    answer: &str,
+    correct: bool,
+) -> io::Result<()> {
+    let invalid = |message: &str| io::Error::new(ErrorKind::InvalidData, message.to_string());
+
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
+    let mut doc: DocumentMut = content
+        .parse()
+        .map_err(|err: toml_edit::TomlError| invalid(&err.to_string()))?;
+
+    let inputs = doc
+        .entry(&day_key(day))
+        .or_insert_with(|| {
+            // only the `[day_XX.input]` headers are written
+            let mut inputs = Table::new();
+            inputs.set_implicit(true);
+            Item::Table(inputs)
+        })
+        .as_table_mut()
+        .ok_or_else(|| invalid("day entry is not a table"))?;
+    let answers = inputs
+        .entry(input)
+        .or_insert(toml_edit::table())
+        .as_table_mut()
+        .ok_or_else(|| invalid("input entry is not a table"))?;
+
+    let key = format!("part{part}");
+    if correct {
+        // answers may be written as integers
+        let existing = answers.get(&key).and_then(Item::as_value).map(|value| {
+            value
+                .as_str()
+                .map_or_else(|| value.to_string().trim().to_string(), str::to_string)
+        });
+        match existing {
+            Some(existing) if existing != answer => {
+                let message = format!("{input} of day {day} already has {key} = {existing}");
+                return Err(io::Error::new(ErrorKind::AlreadyExists, message));
+            }
+            Some(_) => return Ok(()),
+            None => answers[&key] = toml_edit::value(answer),
+        }
+    } else {
+        answers
+            .entry(&format!("part{part}_wrong"))
+            .or_insert(toml_edit::value(Array::new()))
+            .as_array_mut()
+            .ok_or_else(|| invalid("wrong answers are not an array"))?
+            .push(answer);
+    }
+
+    std::fs::write(path, doc.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected() {
+        let registry: AnswerRegistry = toml::from_str(
+            r#"
+            [day_01.input]
+            part1 = "1018"
+            part2 = 5815
+
+            [day_11.test_input_2]
+            part2 = "2"
+
+            [day_02.test_input]
+            error = "invalid value `asdf`"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(Some("1018"), registry.expected(1, "input", 1));
+        assert_eq!(Some("5815"), registry.expected(1, "input", 2));
+        assert_eq!(None, registry.expected(1, "test_input", 1));
+        assert_eq!(None, registry.expected(11, "test_input_2", 1));
+        assert_eq!(Some("2"), registry.expected(11, "test_input_2", 2));
+        assert_eq!(
+            vec!["test_input_2"],
+            registry.inputs(11).collect::<Vec<_>>()
+        );
+        assert_eq!(0, registry.inputs(12).count());
+        assert_eq!(
+            Some("invalid value `asdf`"),
+            registry.expected_error(2, "test_input")
+        );
+        assert_eq!(None, registry.expected(2, "test_input", 1));
+        assert_eq!(None, registry.expected_error(1, "input"));
+    }
+
+    #[test]
+    fn test_params() {
+        let registry: AnswerRegistry = toml::from_str(
+            r#"
+            [day_08.test_input]
+            part1 = "40"
+            params = { pairs = 10, name = "example" }
+            "#,
+        )
+        .unwrap();
+
+        let params = registry.params(8, "test_input");
+        assert_eq!(Some("10"), params.get("pairs").map(String::as_str));
+        assert_eq!(Some("example"), params.get("name").map(String::as_str));
+        assert!(registry.params(8, "input").is_empty());
+        assert!(registry.params(1, "test_input").is_empty());
+    }
+
+    #[test]
+    fn test_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.toml");
+        std::fs::write(&path, "# answers\n[day_01.input]\npart1 = \"1018\"\n").unwrap();
+
+        record(&path, 1, "input", 2, "5000", false).unwrap();
+        record(&path, 1, "input", 2, "6000", false).unwrap();
+        record(&path, 1, "input", 2, "5815", true).unwrap();
+        record(&path, 12, "input", 1, "7", true).unwrap();
+        assert_eq!(
+            "# answers\n[day_01.input]\npart1 = \"1018\"\n\
+             part2_wrong = [\"5000\", \"6000\"]\npart2 = \"5815\"\n\n\
+             [day_12.input]\npart1 = \"7\"\n",
+            std::fs::read_to_string(&path).unwrap()
+        );
+
+        record(&path, 1, "input", 1, "1018", true).unwrap();
+        let err = record(&path, 1, "input", 1, "1019", true).unwrap_err();
+        assert_eq!(ErrorKind::AlreadyExists, err.kind());
+
+        let registry = AnswerRegistry::load(&path).unwrap();
+        assert_eq!(Some("5815"), registry.expected(1, "input", 2));
+        assert!(registry.is_wrong(1, "input", 2, "6000"));
+        assert!(!registry.is_wrong(1, "input", 1, "6000"));
+        assert!(!registry.is_wrong(2, "input", 2, "6000"));
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = AnswerRegistry::load(&dir.path().join("answers.toml")).unwrap();
+        assert_eq!(None, registry.expected(1, "input", 1));
+
+        // a directory can't be read
+        assert!(AnswerRegistry::load(dir.path()).is_err());
+        let path = dir.path().join("broken.toml");
+        std::fs::write(&path, "[day_01.input\n").unwrap();
+        assert_eq!(
+            ErrorKind::InvalidData,
+            AnswerRegistry::load(&path).unwrap_err().kind()
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Environment variable holding the session cookie of a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable replacing `BASE_URL`, e.g. with a local stand-in server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Identifies the tool to the site's maintainers, as asked for automated requests.
+const USER_AGENT: &str = concat!(
+    "github.com/aleksmark98/advent-of-rust-code aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Status { code: u16, body: String },
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session token, set {SESSION_VAR} or write it to ~/.config/aoc/session"
+            ),
+            ClientError::Status { code: 404, .. } => {
+                write!(f, "not found (404), is the puzzle unlocked yet?")
+            }
+            ClientError::Status { code, body } => write!(f, "HTTP {code}: {}", body.trim()),
+            ClientError::Transport(err) => write!(f, "request failed: {err}"),
+            ClientError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(err: io::Error) -> Self {
+        ClientError::Io(err)
+    }
+}
+
+/// Config file holding the session token, `$XDG_CONFIG_HOME/aoc/session` or
+/// `~/.config/aoc/session`.
+pub fn session_file() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_dir.join("aoc").join("session"))
+}
+
+/// Session token from the environment variable if set, from the config file otherwise.
+fn session_token(from_env: Option<String>, file: Option<&Path>) -> Option<String> {
+    from_env
+        .or_else(|| fs::read_to_string(file?).ok())
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+}
+
+/// Authenticated client of the Advent of Code site.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Client configured by `AOC_SESSION` or the session file, and `AOC_BASE_URL`.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let session = session_token(std::env::var(SESSION_VAR).ok(), session_file().as_deref())
+            .ok_or(ClientError::MissingSession)?;
+        let base_url = std::env::var(BASE_URL_VAR).unwrap_or_else(|_| BASE_URL.to_string());
+
+        Ok(Client::new(&base_url, &session))
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.agent
+            .request(method, &format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    fn read(response: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(code, response)) => Err(ClientError::Status {
+                code,
+                body: response.into_string().unwrap_or_default(),
+            }),
+            Err(err) => Err(ClientError::Transport(err.to_string())),
+        }
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        Self::read(
+            self.request("GET", &format!("/{year}/day/{day}/input"))
+                .call(),
+        )
+    }
+
+    /// Posts the answer of a part, returning the page with the site's verdict.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let request = self.request("POST", &format!("/{year}/day/{day}/answer"));
+        Self::read(request.send_form(&[("level", &part.to_string()), ("answer", answer)]))
+    }
+}
+
+/// Downloads the input of the day to `path`, unless it's already there. Returns whether it
+/// was downloaded.
+pub fn fetch_input(client: &Client, year: u16, day: u8, path: &Path) -> Result<bool, ClientError> {
+    // `aoc new` leaves an empty placeholder
+    if fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(false);
+    }
+
+    let input = client.input(year, day)?;
+    fs::write(path, input)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockServer;
+
+    fn site() -> MockServer {
+        MockServer::start(
+            |request| match (request.path.as_str(), request.header("Cookie")) {
+                (_, cookie) if cookie != Some("session=token") => {
+                    (400, "Please log in".to_string())
+                }
+                ("/2025/day/1/input", _) => (200, "L68\nL30\n".to_string()),
+                _ => (404, "404 Not Found".to_string()),
+            },
+        )
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let server = site();
+        let client = Client::new(&server.url, "token");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input");
+
+        assert!(fetch_input(&client, 2025, 1, &path).unwrap());
+        assert_eq!("L68\nL30\n", fs::read_to_string(&path).unwrap());
+        // cached from now on
+        assert!(!fetch_input(&client, 2025, 1, &path).unwrap());
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!("GET", requests[0].method);
+        assert_eq!(Some(USER_AGENT), requests[0].header("User-Agent"));
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let server = site();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input");
+
+        let err = fetch_input(&Client::new(&server.url, "token"), 2025, 2, &path).unwrap_err();
+        assert!(matches!(err, ClientError::Status { code: 404, .. }));
+        let err = fetch_input(&Client::new(&server.url, "bad"), 2025, 1, &path).unwrap_err();
+        assert_eq!("HTTP 400: Please log in", err.to_string());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_session_token() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("session");
+        fs::write(&file, "from_file\n").unwrap();
+
+        assert_eq!(
+            Some("from_env".to_string()),
+            session_token(Some("from_env".to_string()), Some(&file))
+        );
+        assert_eq!(
+            Some("from_file".to_string()),
+            session_token(None, Some(&file))
+        );
+        assert_eq!(None, session_token(None, Some(&dir.path().join("missing"))));
+        assert_eq!(None, session_token(Some(" ".to_string()), None));
+    }
+}
@@ -0,0 +1,210 @@
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+use crate::answers;
+
+/// Example of a part, as given in the puzzle description.
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub input: String,
+    pub answer: Option<String>,
+}
+
+/// Text between each `open` and the following `close`, with the offset of `open`.
+fn between<'a>(
+    html: &'a str,
+    open: &'a str,
+    close: &'a str,
+) -> impl Iterator<Item = (usize, &'a str)> {
+    html.match_indices(open).filter_map(move |(start, _)| {
+        let inner = &html[start + open.len()..];
+        inner.find(close).map(|end| (start, &inner[..end]))
+    })
+}
+
+/// Text of an HTML fragment, without markup and with the entities decoded.
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Examples of each part, one per `<article>` of the page. The example input is the first
+/// `<pre><code>` block of the article, or the previous article's if it has none, and the
+/// answer the last emphasized `<code>`, which is where the descriptions conclude.
+pub fn extract(page: &str) -> Vec<Example> {
+    let mut previous: Option<String> = None;
+    between(page, "<article", "</article>")
+        .filter_map(|(_, article)| {
+            // part 2 often goes on with the example of part 1
+            let input = between(article, "<pre><code>", "</code></pre>")
+                .next()
+                .map(|(_, input)| text(input))
+                .or_else(|| previous.clone())?;
+            previous = Some(input.clone());
+            let answer = between(article, "<code><em>", "</em></code>")
+                .chain(between(article, "<em><code>", "</code></em>"))
+                .max_by_key(|&(start, _)| start)
+                .map(|(_, answer)| text(answer));
+            Some(Example { input, answer })
+        })
+        .collect()
+}
+
+/// Writes the examples into the test input files of `day_dir` and registers their answers:
+/// `test_input` when both parts share the example, `test_input_1` and `test_input_2`
+/// otherwise. Returns the names of the files.
+pub fn save(
+    examples: &[Example],
+    day_dir: &Path,
+    answers_path: &Path,
+    day: u8,
+) -> io::Result<Vec<String>> {
+    let shared = match examples {
+        [] => {
+            return Err(io::Error::new(
+                ErrorKind::NotFound,
+                "no example in the page",
+            ));
+        }
+        [_] => true,
+        [first, second, ..] => first.input == second.input,
+    };
+    let files: Vec<(String, u8, &Example)> = examples
+        .iter()
+        .take(2)
+        .zip(1..)
+        .map(|(example, part)| {
+            let name = if shared {
+                "test_input".to_string()
+            } else {
+                format!("test_input_{part}")
+            };
+            (name, part, example)
+        })
+        .collect();
+
+    // `aoc new` leaves an empty placeholder, anything else is kept
+    for (name, _, example) in &files {
+        let path = day_dir.join(name);
+        if fs::read_to_string(&path).is_ok_and(|old| !old.is_empty() && old != example.input) {
+            return Err(io::Error::new(
+                ErrorKind::AlreadyExists,
+                format!("{} already exists with another example", path.display()),
+            ));
+        }
+    }
+
+    let mut names: Vec<String> = Vec::new();
+    for (name, part, example) in files {
+        fs::write(day_dir.join(&name), &example.input)?;
+        if let Some(answer) = &example.answer {
+            answers::record(answers_path, day, &name, part, answer, true)?;
+        }
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Secret Entrance ---</h2>
+<p>For example, suppose the attached document contained the following rotations:</p>
+<pre><code>L68
+L30
+R48
+</code></pre>
+<p>Because the dial points at <code>0</code> a total of three times during this process,
+the password in this example is <code><em>3</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1018</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Following the same rotations as in the above example, the dial points at zero a few
+extra times during its rotations:</p>
+<pre><code>L68 &lt;- <em>one</em> click
+</code></pre>
+<p>In this example, the new password would be <em><code>6</code></em>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            vec![
+                Example {
+                    input: "L68\nL30\nR48\n".to_string(),
+                    answer: Some("3".to_string()),
+                },
+                Example {
+                    input: "L68 <- one click\n".to_string(),
+                    answer: Some("6".to_string()),
+                },
+            ],
+            extract(PAGE)
+        );
+        assert!(extract("<html>no puzzle</html>").is_empty());
+
+        let reused = PAGE.replace("<pre><code>L68 &lt;- <em>one</em> click\n</code></pre>", "");
+        let examples = extract(&reused);
+        assert_eq!(examples[0].input, examples[1].input);
+        assert_eq!(Some("6".to_string()), examples[1].answer);
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let answers_path = dir.path().join("answers.toml");
+        let example = |input: &str, answer: &str| Example {
+            input: input.to_string(),
+            answer: Some(answer.to_string()),
+        };
+
+        let shared = [example("1\n", "3"), example("1\n", "6")];
+        assert_eq!(
+            vec!["test_input"],
+            save(&shared, dir.path(), &answers_path, 1).unwrap()
+        );
+        let separate = [example("1\n", "5"), example("2\n", "2")];
+        assert_eq!(
+            vec!["test_input_1", "test_input_2"],
+            save(&separate, dir.path(), &answers_path, 11).unwrap()
+        );
+        assert_eq!(
+            "2\n",
+            fs::read_to_string(dir.path().join("test_input_2")).unwrap()
+        );
+
+        let registry = crate::answers::AnswerRegistry::load(&answers_path).unwrap();
+        assert_eq!(Some("3"), registry.expected(1, "test_input", 1));
+        assert_eq!(Some("6"), registry.expected(1, "test_input", 2));
+        assert_eq!(Some("2"), registry.expected(11, "test_input_2", 2));
+        assert_eq!(None, registry.expected(11, "test_input_2", 1));
+
+        let err = save(&[example("2\n", "3")], dir.path(), &answers_path, 1).unwrap_err();
+        assert_eq!(ErrorKind::AlreadyExists, err.kind());
+
+        // saving the page again after part 1 registers the same answer
+        save(&shared, dir.path(), &answers_path, 1).unwrap();
+        let err = save(&[example("1\n", "4")], dir.path(), &answers_path, 1).unwrap_err();
+        assert_eq!(ErrorKind::AlreadyExists, err.kind());
+    }
+}
@@ -0,0 +1,327 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+
+/// Random puzzle inputs of a day, in the format of its puzzle input.
+#[derive(Clone, Copy)]
+pub struct Generator {
+    /// Size of the puzzle input, in the unit of `generate`.
+    pub size: usize,
+    generate: fn(&mut StdRng, usize) -> String,
+}
+
+impl Generator {
+    /// Generates an input of `size`, the same for the same seed.
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.generate)(&mut StdRng::seed_from_u64(seed), size)
+    }
+}
+
+pub fn generator(year: u16, day: u8) -> Option<Generator> {
+    let (size, generate): (usize, fn(&mut StdRng, usize) -> String) = match (year, day) {
+        (2025, 1) => (4046, day_01),
+        (2025, 2) => (30, day_02),
+        (2025, 3) => (200, day_03),
+        (2025, 4) => (139, day_04),
+        (2025, 5) => (190, day_05),
+        (2025, 6) => (1000, day_06),
+        (2025, 7) => (70, day_07),
+        (2025, 8) => (1000, day_08),
+        (2025, 9) => (496, day_09),
+        (2025, 10) => (194, day_10),
+        (2025, 11) => (560, day_11),
+        _ => return None,
+    };
+
+    Some(Generator { size, generate })
+}
+
+/// `size` rotations of the dial.
+fn day_01(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = if rng.random_bool(0.5) { 'L' } else { 'R' };
+            format!("{direction}{}\n", rng.random_range(1..1000))
+        })
+        .collect()
+}
+
+/// `size` ID ranges of up to a quarter million IDs.
+fn day_02(rng: &mut StdRng, size: usize) -> String {
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = rng.random_range(1..=10);
+            let start = rng.random_range(1..10u64.pow(digits));
+            format!("{start}-{}", start + rng.random_range(0..250_000))
+        })
+        .collect();
+    format!("{}\n", ranges.join(","))
+}
+
+/// `size` banks of 100 batteries.
+fn day_03(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut bank: String = (0..100)
+                .map(|_| char::from(b'0' + rng.random_range(1..=9)))
+                .collect();
+            bank.push('\n');
+            bank
+        })
+        .collect()
+}
+
+/// A square grid of `size` rows, two thirds of it rolls.
+fn day_04(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..size)
+                .map(|_| if rng.random_bool(0.65) { '@' } else { '.' })
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+/// `size` fresh ID ranges, then five times as many available IDs.
+fn day_05(rng: &mut StdRng, size: usize) -> String {
+    const MAX_ID: u64 = 500_000_000_000_000;
+
+    let mut input = String::new();
+    for _ in 0..size {
+        let start = rng.random_range(1..MAX_ID);
+        let end = start + rng.random_range(0..2_000_000_000_000);
+        writeln!(input, "{start}-{end}").unwrap();
+    }
+    input.push('\n');
+    for _ in 0..5 * size {
+        writeln!(input, "{}", rng.random_range(1..MAX_ID)).unwrap();
+    }
+    input
+}
+
+/// `size` problems of 4 numbers, each aligned to the left or right of its column.
+fn day_06(rng: &mut StdRng, size: usize) -> String {
+    let mut rows = vec![String::new(); 5];
+    for problem in 0..size {
+        let width = rng.random_range(1..=4);
+        let left_aligned = rng.random_bool(0.5);
+        if problem > 0 {
+            rows.iter_mut().for_each(|row| row.push(' '));
+        }
+
+        // at least one number is as wide as the column
+        let widest = rng.random_range(0..4);
+        for (idx, row) in rows[..4].iter_mut().enumerate() {
+            let digits = if idx == widest {
+                width
+            } else {
+                rng.random_range(1..=width)
+            };
+            let number: String = (0..digits)
+                .map(|_| char::from(b'0' + rng.random_range(1..=9)))
+                .collect();
+            if left_aligned {
+                write!(row, "{number:<width$}").unwrap();
+            } else {
+                write!(row, "{number:>width$}").unwrap();
+            }
+        }
+
+        let operator = if rng.random_bool(0.5) { '+' } else { '*' };
+        write!(rows[4], "{operator:<width$}").unwrap();
+    }
+
+    rows.iter().map(|row| format!("{row}\n")).collect()
+}
+
+/// A pyramid of splitters below the start, `size` rows of them with two thirds of them
+/// kept.
+fn day_07(rng: &mut StdRng, size: usize) -> String {
+    let width = 2 * size + 1;
+    let mut input = format!("{:.<size$}S{:.<size$}\n", "", "");
+    for row in 0..size {
+        input.push_str(&".".repeat(width));
+        input.push('\n');
+        let mut splitters = vec!['.'; width];
+        for col in (size - row..=size + row).step_by(2) {
+            if row == 0 || rng.random_bool(0.66) {
+                splitters[col] = '^';
+            }
+        }
+        input.extend(splitters);
+        input.push('\n');
+    }
+    input.push_str(&".".repeat(width));
+    input.push('\n');
+    input
+}
+
+/// `size` junction boxes in a cube of 100000.
+fn day_08(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let [x, y, z] = [(); 3].map(|_| rng.random_range(0..100_000));
+            format!("{x},{y},{z}\n")
+        })
+        .collect()
+}
+
+/// A polygon of about `size` red tiles, a band of columns between a top and a bottom
+/// staircase.
+fn day_09(rng: &mut StdRng, size: usize) -> String {
+    const HEIGHT: u32 = 100_000;
+
+    let columns = (size / 4).max(1);
+    let max_gap = (2 * HEIGHT / columns as u32).max(1);
+    let mut x = rng.random_range(0..=max_gap);
+    let mut xs = vec![x];
+    for _ in 0..columns {
+        x += rng.random_range(1..=max_gap);
+        xs.push(x);
+    }
+
+    // tops are all above the bottoms, neighbouring columns always overlap
+    let mut steps = |range: std::ops::Range<u32>| {
+        let mut heights: Vec<u32> = Vec::with_capacity(columns);
+        while heights.len() < columns {
+            let height = rng.random_range(range.clone());
+            if heights.last() != Some(&height) {
+                heights.push(height);
+            }
+        }
+        heights
+    };
+    let tops = steps(HEIGHT / 2 + 1..HEIGHT);
+    let bottoms = steps(0..HEIGHT / 2);
+
+    let mut input = String::new();
+    for (col, top) in tops.iter().enumerate() {
+        writeln!(input, "{},{top}", xs[col]).unwrap();
+        writeln!(input, "{},{top}", xs[col + 1]).unwrap();
+    }
+    for (col, bottom) in bottoms.iter().enumerate().rev() {
+        writeln!(input, "{},{bottom}", xs[col + 1]).unwrap();
+        writeln!(input, "{},{bottom}", xs[col]).unwrap();
+    }
+    input
+}
+
+/// `size` machines, their lights and joltages reached by pressing random buttons.
+fn day_10(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let lights = rng.random_range(4..=10);
+        let buttons: Vec<Vec<usize>> = (0..rng.random_range(2..=13))
+            .map(|_| {
+                let toggled = rng.random_range(1..lights);
+                let mut button = rand::seq::index::sample(rng, lights, toggled).into_vec();
+                button.sort_unstable();
+                button
+            })
+            .collect();
+
+        let mut on = vec![false; lights];
+        let mut joltage = vec![0; lights];
+        for button in &buttons {
+            let presses = rng.random_range(0..=30);
+            for &light in button {
+                on[light] ^= presses % 2 == 1;
+                joltage[light] += presses;
+            }
+        }
+
+        let on: String = on.iter().map(|&on| if on { '#' } else { '.' }).collect();
+        write!(input, "[{on}]").unwrap();
+        for button in buttons {
+            let button: Vec<String> = button.iter().map(usize::to_string).collect();
+            write!(input, " ({})", button.join(",")).unwrap();
+        }
+        let joltage: Vec<String> = joltage.iter().map(usize::to_string).collect();
+        writeln!(input, " {{{}}}", joltage.join(",")).unwrap();
+    }
+    input
+}
+
+/// `size` devices in 20 levels, each connected to devices of the next level so that the
+/// number of paths stays small.
+fn day_11(rng: &mut StdRng, size: usize) -> String {
+    const LEVELS: usize = 20;
+    const SPECIAL: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+
+    // names get a letter longer once three run out
+    let mut len = 3;
+    while 26usize.pow(len) < 2 * size + SPECIAL.len() {
+        len += 1;
+    }
+    let mut names: HashSet<String> = SPECIAL.iter().map(|name| name.to_string()).collect();
+    let mut levels = vec![Vec::new(); LEVELS];
+    for device in 0..size.max(LEVELS) {
+        let name = loop {
+            let name: String = (0..len)
+                .map(|_| char::from(b'a' + rng.random_range(0..26)))
+                .collect();
+            if names.insert(name.clone()) {
+                break name;
+            }
+        };
+        levels[device % LEVELS].push(name);
+    }
+    let (first, second) = if rng.random_bool(0.5) {
+        ("fft", "dac")
+    } else {
+        ("dac", "fft")
+    };
+    for (level, name) in [(0, "svr"), (2, "you"), (7, first), (13, second)] {
+        levels[level][0] = name.to_string();
+    }
+
+    let mut lines: Vec<String> = Vec::with_capacity(size);
+    for (level, devices) in levels.iter().enumerate() {
+        for device in devices {
+            let fan_out = rng.random_range(1..=3);
+            let outputs: Vec<&str> = match levels.get(level + 1) {
+                Some(next) => next
+                    .choose_multiple(rng, fan_out)
+                    .map(String::as_str)
+                    .collect(),
+                None => vec!["out"],
+            };
+            lines.push(format!("{device}: {}\n", outputs.join(" ")));
+        }
+    }
+    lines.shuffle(rng);
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Overrides;
+
+    #[test]
+    fn test_generate() {
+        for day in 1..=11 {
+            let generator = generator(2025, day).unwrap();
+            let input = generator.generate(generator.size / 10 + 1, 1);
+            assert_eq!(input, generator.generate(generator.size / 10 + 1, 1));
+            assert_ne!(input, generator.generate(generator.size / 10 + 1, 2));
+
+            // day 10 solves its machines with z3
+            let part = if day == 10 { 1 } else { 2 };
+            let solve = crate::solver(2025, day).unwrap().solve;
+            let mut overrides = Overrides::new();
+            if day == 8 {
+                overrides.insert("pairs".to_string(), "10".to_string());
+            }
+            if let Err(err) = solve(&input, part, &overrides) {
+                panic!("day {day}: {err}");
+            }
+        }
+        assert!(generator(2024, 1).is_none());
+    }
+}
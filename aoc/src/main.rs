@@ -0,0 +1,721 @@
+mod allocations;
+mod answers;
+mod client;
+mod examples;
+mod generate;
+#[cfg(test)]
+mod mock;
+mod output;
+mod report;
+mod scaffold;
+mod submit;
+mod watch;
+
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use aoc_core::{Overrides, Params, ParseError, Solution};
+use clap::{Parser, Subcommand};
+
+use answers::AnswerRegistry;
+use client::Client;
+use output::{Format, Printer, Record, Status};
+
+/// Repository root, the workspace holding a directory of `day_XX` crates per year.
+const ROOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: allocations::CountingAlloc = allocations::CountingAlloc;
+
+/// Year of the commands run without `--year`.
+const DEFAULT_YEAR: u16 = 2025;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or all days
+    Run {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        /// Day to solve, all days if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part to solve, both parts if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// `real` for the puzzle input, `test` for the example or a file name in the day's directory
+        #[arg(long, default_value = "real")]
+        input: String,
+        /// Puzzle parameter as `NAME=VALUE`, over the ones of the input in the answer registry
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param, requires = "day")]
+        params: Vec<(String, String)>,
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Check every input of one or all days against the answer registry
+    Verify {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        /// Day to verify, all days if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Answer registry, `answers.toml` in the year's directory if omitted
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Create the crate of a new day from the template
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Year of the day, its directory is created if it doesn't exist yet
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+    },
+    /// Time every day on its puzzle input and print a markdown table, run it with `--release`
+    Report {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        /// Runs of each day, the median is reported
+        #[arg(long, default_value_t = 10)]
+        iterations: u32,
+        /// Also write the table into the marked section of the README
+        #[arg(long)]
+        readme: bool,
+        /// Count the allocations of each step instead, needs the `alloc-stats` feature
+        #[arg(long, conflicts_with = "readme")]
+        allocations: bool,
+    },
+    /// Submit the answer of a part on the puzzle input and record the verdict
+    Submit {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Extract the examples and their answers from a saved puzzle page
+    Examples {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        /// Puzzle page saved from the browser
+        page: PathBuf,
+    },
+    /// Verify a day again on every change of its sources, inputs or expected answers
+    Watch {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Build the runner with `--release`
+        #[arg(long)]
+        release: bool,
+    },
+    /// Print a random input of a day in the format of its puzzle input
+    Generate {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Size in the day's unit, e.g. rotations or grid rows, the puzzle input's if omitted
+        #[arg(long)]
+        size: Option<usize>,
+        /// Seed of the random generator, the same seed generates the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Download the puzzle input of a day, unless it's already there
+    Fetch {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+    },
+}
+
+fn parse_param(arg: &str) -> Result<(String, String), String> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected `NAME=VALUE`, got `{arg}`"))?;
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+/// Parses the puzzle input with the day's parameters and solves the given part.
+fn solve<S: Solution>(input: &str, part: u8, overrides: &Overrides) -> Result<String, ParseError> {
+    let input = S::parse_with(input, &S::Params::with(overrides)?)?;
+    Ok(match part {
+        1 => S::part1(&input).to_string(),
+        _ => S::part2(&input).to_string(),
+    })
+}
+
+/// Entry points of a solved day, independent of the types of its `Solution`.
+#[derive(Clone, Copy)]
+struct Solver {
+    solve: fn(&str, u8, &Overrides) -> Result<String, ParseError>,
+    time: fn(&str, &Overrides, u32) -> Result<report::Timings, ParseError>,
+    allocations: fn(&str, &Overrides) -> Result<report::StepAllocations, ParseError>,
+}
+
+impl Solver {
+    fn of<S: Solution>() -> Self {
+        Solver {
+            solve: solve::<S>,
+            time: report::time::<S>,
+            allocations: report::allocations::<S>,
+        }
+    }
+}
+
+fn solver(year: u16, day: u8) -> Option<Solver> {
+    let solver = match (year, day) {
+        (2025, 1) => Solver::of::<y2025_day_01::Day01>(),
+        (2025, 2) => Solver::of::<y2025_day_02::Day02>(),
+        (2025, 3) => Solver::of::<y2025_day_03::Day03>(),
+        (2025, 4) => Solver::of::<y2025_day_04::Day04>(),
+        (2025, 5) => Solver::of::<y2025_day_05::Day05>(),
+        (2025, 6) => Solver::of::<y2025_day_06::Day06>(),
+        (2025, 7) => Solver::of::<y2025_day_07::Day07>(),
+        (2025, 8) => Solver::of::<y2025_day_08::Day08>(),
+        (2025, 9) => Solver::of::<y2025_day_09::Day09>(),
+        (2025, 10) => Solver::of::<y2025_day_10::Day10>(),
+        (2025, 11) => Solver::of::<y2025_day_11::Day11>(),
+        _ => return None,
+    };
+
+    Some(solver)
+}
+
+fn repo_root() -> PathBuf {
+    Path::new(ROOT_DIR)
+        .canonicalize()
+        .expect("Cannot resolve the repository root")
+}
+
+/// The given day, or every day of the year with a solver.
+fn days(year: u16, day: Option<u8>) -> Vec<u8> {
+    match day {
+        Some(day) => vec![day],
+        None => (1..=25)
+            .filter(|&day| solver(year, day).is_some())
+            .collect(),
+    }
+}
+
+fn year_dir(year: u16) -> PathBuf {
+    Path::new(ROOT_DIR).join(year.to_string())
+}
+
+fn day_dir(year: u16, day: u8) -> PathBuf {
+    year_dir(year).join(format!("day_{day:02}"))
+}
+
+/// Resolves the `--input` argument to a file in the day's directory. Days with a separate
+/// example per part keep them in `test_input_1` and `test_input_2`.
+fn input_path(day_dir: &Path, input: &str, part: u8) -> PathBuf {
+    match input {
+        "real" => day_dir.join("input"),
+        "test" => {
+            let per_part = day_dir.join(format!("test_input_{part}"));
+            if per_part.exists() {
+                per_part
+            } else {
+                day_dir.join("test_input")
+            }
+        }
+        file_name => day_dir.join(file_name),
+    }
+}
+
+/// Solves a part of the day from `input`, the text of `file`.
+fn run(year: u16, day: u8, part: u8, file: &Path, input: &str, overrides: &Overrides) -> Record {
+    let file_name = file.file_name().unwrap_or_default().to_string_lossy();
+    let Some(solver) = solver(year, day) else {
+        return Record::new(year, day, part, &file_name, Status::NotSolved);
+    };
+
+    let start = Instant::now();
+    match (solver.solve)(input, part, overrides) {
+        Ok(answer) => Record {
+            answer: Some(answer),
+            elapsed: Some(start.elapsed()),
+            ..Record::new(year, day, part, &file_name, Status::Solved)
+        },
+        Err(err) => Record {
+            error: Some(err.in_file(file).to_string()),
+            ..Record::new(year, day, part, &file_name, Status::Error)
+        },
+    }
+}
+
+/// Solves a part of the day from the file named by `--input`, with the parameters registered
+/// for the file and then `params`.
+fn run_file(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    answers: &AnswerRegistry,
+    params: &Overrides,
+) -> Record {
+    let file = input_path(&day_dir(year, day), input, part);
+    let file_name = file.file_name().unwrap_or_default().to_string_lossy();
+    let Ok(text) = std::fs::read_to_string(&file) else {
+        return Record::new(year, day, part, &file_name, Status::MissingInput);
+    };
+
+    let mut overrides = answers.params(day, &file_name);
+    overrides.extend(params.clone());
+    run(year, day, part, &file, &text, &overrides)
+}
+
+/// Input files of a day: the puzzle input, the examples and any file with registered answers.
+fn input_files(year: u16, day: u8, answers: &AnswerRegistry) -> Vec<String> {
+    let mut files: Vec<String> = std::fs::read_dir(day_dir(year, day))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name == "input" || name.starts_with("test_input"))
+        .chain(answers.inputs(day).map(String::from))
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Parts an input file is meant for, `test_input_N` only holds the example of part N.
+fn parts(file_name: &str) -> RangeInclusive<u8> {
+    match file_name
+        .strip_prefix("test_input_")
+        .and_then(|part| part.parse().ok())
+    {
+        Some(part @ 1..=2) => part..=part,
+        _ => 1..=2,
+    }
+}
+
+fn check(answer: &str, expected: Option<&str>) -> Status {
+    match expected {
+        Some(expected) if expected == answer => Status::Pass,
+        Some(_) => Status::Fail,
+        None => Status::Unknown,
+    }
+}
+
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    unknown: usize,
+}
+
+fn verify(year: u16, day: u8, answers: &AnswerRegistry, tally: &mut Tally, printer: &mut Printer) {
+    let Some(solver) = solver(year, day) else {
+        // e.g. a day added by hand, not registered in `solver()`
+        for part in 1..=2 {
+            tally.unknown += 1;
+            printer.print(Record::new(year, day, part, "", Status::NotSolved));
+        }
+        return;
+    };
+
+    for file_name in input_files(year, day, answers) {
+        let file = day_dir(year, day).join(&file_name);
+        for part in parts(&file_name) {
+            let record = match std::fs::read_to_string(&file) {
+                Err(_) => Record::new(year, day, part, &file_name, Status::MissingInput),
+                Ok(input) => {
+                    let start = Instant::now();
+                    let expected_error = answers.expected_error(day, &file_name);
+                    match (solver.solve)(&input, part, &answers.params(day, &file_name)) {
+                        // the input was meant to be rejected
+                        Ok(answer) if expected_error.is_some() => Record {
+                            answer: Some(answer),
+                            expected: expected_error.map(|error| format!("error: {error}")),
+                            ..Record::new(year, day, part, &file_name, Status::Fail)
+                        },
+                        Ok(answer) => {
+                            let expected = answers.expected(day, &file_name, part);
+                            let status = check(&answer, expected);
+                            Record {
+                                answer: Some(answer),
+                                expected: expected.map(String::from),
+                                elapsed: Some(start.elapsed()),
+                                ..Record::new(year, day, part, &file_name, status)
+                            }
+                        }
+                        Err(err)
+                            if expected_error
+                                .is_some_and(|error| err.message.starts_with(error)) =>
+                        {
+                            Record {
+                                answer: Some(format!("error: {}", err.message)),
+                                expected: expected_error.map(|error| format!("error: {error}")),
+                                ..Record::new(year, day, part, &file_name, Status::Pass)
+                            }
+                        }
+                        Err(err) => Record {
+                            error: Some(err.in_file(&file).to_string()),
+                            ..Record::new(year, day, part, &file_name, Status::Error)
+                        },
+                    }
+                }
+            };
+
+            match record.status {
+                Status::Pass => tally.passed += 1,
+                Status::Fail | Status::Error => tally.failed += 1,
+                _ => tally.unknown += 1,
+            }
+            printer.print(record);
+        }
+    }
+}
+
+fn fail(err: impl Display) -> ! {
+    eprintln!("error: {err}");
+    std::process::exit(1)
+}
+
+/// The answer registry at `path`, `answers.toml` of the year if `None`.
+fn load_answers(year: u16, path: Option<PathBuf>) -> AnswerRegistry {
+    let path = path.unwrap_or_else(|| year_dir(year).join("answers.toml"));
+    AnswerRegistry::load(&path)
+        .unwrap_or_else(|err| fail(format_args!("{}: {err}", path.display())))
+}
+
+/// Directory of a day of any year, which must have been created with `aoc new`.
+fn created_day_dir(year: u16, day: u8) -> PathBuf {
+    let day_dir = repo_root()
+        .join(year.to_string())
+        .join(format!("day_{day:02}"));
+    if !day_dir.is_dir() {
+        fail(format_args!(
+            "{} doesn't exist, create it with `aoc new {day} --year {year}`",
+            day_dir.display()
+        ));
+    }
+    day_dir
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            params,
+            format,
+        } => {
+            let parts = part.map_or(1..=2, |part| part..=part);
+            let params: Overrides = params.into_iter().collect();
+            let mut printer = Printer::new(format);
+            if input == "-" {
+                let Some(day) = day else {
+                    fail("`--input -` needs `--day`");
+                };
+                let text =
+                    std::io::read_to_string(std::io::stdin()).unwrap_or_else(|err| fail(err));
+                for part in parts {
+                    printer.print(run(year, day, part, Path::new("<stdin>"), &text, &params));
+                }
+            } else {
+                let answers = load_answers(year, None);
+                for day in days(year, day) {
+                    for part in parts.clone() {
+                        printer.print(run_file(year, day, part, &input, &answers, &params));
+                    }
+                }
+            }
+            printer.finish();
+        }
+        Command::Verify {
+            year,
+            day,
+            answers,
+            format,
+        } => {
+            let answers = load_answers(year, answers);
+
+            let mut tally = Tally::default();
+            let mut printer = Printer::new(format);
+            for day in days(year, day) {
+                verify(year, day, &answers, &mut tally, &mut printer);
+            }
+            printer.finish();
+
+            // the records are the whole output of the JSON formats
+            if format == Format::Text {
+                println!(
+                    "{} passed, {} failed, {} unknown",
+                    tally.passed, tally.failed, tally.unknown
+                );
+            }
+            if tally.failed > 0 {
+                std::process::exit(1);
+            }
+        }
+        Command::New { day, year } => match scaffold::new_day(&repo_root(), year, day) {
+            Ok(day_dir) => {
+                println!("created {}", day_dir.display());
+                println!("registered it in aoc/Cargo.toml and `solver()` of aoc/src/main.rs");
+            }
+            Err(err) => fail(err),
+        },
+        Command::Report {
+            year,
+            allocations: true,
+            ..
+        } => {
+            if !allocations::ENABLED {
+                fail("counting allocations needs `--features alloc-stats`");
+            }
+
+            let answers = load_answers(year, None);
+            let mut days_allocations = Vec::new();
+            for day in days(year, None) {
+                let file = day_dir(year, day).join("input");
+                let Ok(input) = std::fs::read_to_string(&file) else {
+                    eprintln!("day {day:02}: missing input file");
+                    continue;
+                };
+                let count = solver(year, day).expect("Days are solved").allocations;
+                match count(&input, &answers.params(day, "input")) {
+                    Ok(steps) => days_allocations.push((day, steps)),
+                    Err(err) => eprintln!("day {day:02}: error: {}", err.in_file(&file)),
+                }
+            }
+            print!("{}", report::allocation_table(&days_allocations));
+        }
+        Command::Report {
+            year,
+            iterations,
+            readme,
+            allocations: false,
+        } => {
+            if cfg!(debug_assertions) {
+                eprintln!("warning: debug build, run with `--release` for meaningful timings");
+            }
+
+            let answers = load_answers(year, None);
+            let mut timings = Vec::new();
+            for day in days(year, None) {
+                let file = day_dir(year, day).join("input");
+                let Ok(input) = std::fs::read_to_string(&file) else {
+                    eprintln!("day {day:02}: missing input file");
+                    continue;
+                };
+                let time = solver(year, day).expect("Days are solved").time;
+                match time(&input, &answers.params(day, "input"), iterations) {
+                    Ok(day_timings) => timings.push((day, day_timings)),
+                    Err(err) => eprintln!("day {day:02}: error: {}", err.in_file(&file)),
+                }
+            }
+
+            let table = report::table(&timings, iterations);
+            print!("{table}");
+            if readme {
+                let path = repo_root().join("README.md");
+                let content = std::fs::read_to_string(&path).unwrap_or_else(|err| fail(err));
+                let Some(updated) = report::update_readme(&content, &table) else {
+                    fail(format_args!(
+                        "no `{}` and `{}` markers in {}",
+                        report::README_START,
+                        report::README_END,
+                        path.display()
+                    ));
+                };
+                std::fs::write(&path, updated).unwrap_or_else(|err| fail(err));
+            }
+        }
+        Command::Submit { year, day, part } => {
+            let Some(solver) = solver(year, day) else {
+                fail(format_args!("day {day:02} of {year} is not solved yet"));
+            };
+            let file = day_dir(year, day).join("input");
+            let file_path = file.to_str().expect("Non UTF-8 input path");
+            let input = aoc_core::read_input(file_path).unwrap_or_else(|err| fail(err));
+            let params = load_answers(year, None).params(day, "input");
+            let answer = (solver.solve)(&input, part, &params)
+                .unwrap_or_else(|err| fail(err.in_file(file_path)));
+
+            let client = Client::from_env().unwrap_or_else(|err| fail(err));
+            let store = submit::Store {
+                year,
+                answers: year_dir(year).join("answers.toml"),
+                // the site throttles answers of every year together
+                throttle: Path::new(ROOT_DIR).join(".submit_throttle"),
+            };
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Clock before 1970")
+                .as_secs();
+            match submit::submit(&client, &store, day, part, &answer, now) {
+                Ok(verdict) => println!("day {day:02} part {part} {answer}: {verdict}"),
+                Err(err) => fail(format_args!("day {day:02} part {part} {answer}: {err}")),
+            }
+        }
+        Command::Examples { day, year, page } => {
+            let day_dir = created_day_dir(year, day);
+
+            let page = std::fs::read_to_string(&page).unwrap_or_else(|err| fail(err));
+            let examples = examples::extract(&page);
+            let answers = day_dir.with_file_name("answers.toml");
+            match examples::save(&examples, &day_dir, &answers, day) {
+                Ok(files) => {
+                    for (part, example) in (1..).zip(examples.iter().take(2)) {
+                        let answer = example.answer.as_deref().unwrap_or("not found");
+                        println!(
+                            "part {part}: {} lines, answer {answer}",
+                            example.input.lines().count()
+                        );
+                    }
+                    println!("wrote {} in {}", files.join(", "), day_dir.display());
+                }
+                Err(err) => fail(err),
+            }
+        }
+        Command::Fetch { day, year } => {
+            let day_dir = created_day_dir(year, day);
+
+            let input = day_dir.join("input");
+            let client = Client::from_env().unwrap_or_else(|err| fail(err));
+            match client::fetch_input(&client, year, day, &input) {
+                Ok(true) => println!("downloaded {}", input.display()),
+                Ok(false) => println!("{} already downloaded", input.display()),
+                Err(err) => fail(err),
+            }
+        }
+        Command::Generate {
+            year,
+            day,
+            size,
+            seed,
+        } => {
+            let Some(generator) = generate::generator(year, day) else {
+                fail(format!("no input generator for day {day:02} of {year}"));
+            };
+            print!(
+                "{}",
+                generator.generate(size.unwrap_or(generator.size), seed)
+            );
+        }
+        Command::Watch { year, day, release } => {
+            created_day_dir(year, day);
+            watch::watch(&repo_root(), year, day, release).unwrap_or_else(|err| fail(err));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(
+            day_dir(2025, 1).join("input"),
+            input_path(&day_dir(2025, 1), "real", 1)
+        );
+        assert_eq!(
+            day_dir(2025, 1).join("test_input"),
+            input_path(&day_dir(2025, 1), "test", 2)
+        );
+        assert_eq!(
+            day_dir(2025, 11).join("test_input_2"),
+            input_path(&day_dir(2025, 11), "test", 2)
+        );
+        assert_eq!(
+            day_dir(2025, 11).join("my_input"),
+            input_path(&day_dir(2025, 11), "my_input", 1)
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let input = std::fs::read_to_string(input_path(&day_dir(2025, 1), "test", 1)).unwrap();
+        let solve = solver(2025, 1).unwrap().solve;
+        let defaults = Overrides::new();
+        assert_eq!("3", solve(&input, 1, &defaults).unwrap());
+        assert_eq!("6", solve(&input, 2, &defaults).unwrap());
+        assert_eq!("1", solve("L50\n", 1, &defaults).unwrap());
+        assert!(solve("L50\nX5\n", 1, &defaults).is_err());
+        assert!(solver(2025, 12).is_none());
+        assert!(solver(2024, 1).is_none());
+    }
+
+    #[test]
+    fn test_solver_params() {
+        let solve = solver(2025, 1).unwrap().solve;
+        let start = |value: &str| Overrides::from([("start".to_string(), value.to_string())]);
+        assert_eq!("0", solve("L50\n", 1, &start("0")).unwrap());
+        assert!(solve("L50\n", 1, &start("100")).is_err());
+
+        // the example of day 8 only connects 10 pairs
+        let answers = load_answers(2025, None);
+        let input = std::fs::read_to_string(input_path(&day_dir(2025, 8), "test", 1)).unwrap();
+        let solve = solver(2025, 8).unwrap().solve;
+        assert_eq!(
+            "40",
+            solve(&input, 1, &answers.params(8, "test_input")).unwrap()
+        );
+        assert!(solve(&input, 1, &Overrides::new()).is_err());
+    }
+
+    #[test]
+    fn test_parse_param() {
+        assert_eq!(
+            Ok(("pairs".to_string(), "10".to_string())),
+            parse_param("pairs=10")
+        );
+        assert!(parse_param("pairs").is_err());
+    }
+
+    #[test]
+    fn test_days() {
+        assert_eq!(vec![12], days(2025, Some(12)));
+        assert_eq!((1..=11).collect::<Vec<_>>(), days(2025, None));
+        assert!(days(2015, None).is_empty());
+    }
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(1..=2, parts("input"));
+        assert_eq!(1..=2, parts("test_input"));
+        assert_eq!(2..=2, parts("test_input_2"));
+        assert_eq!(1..=2, parts("test_input_old"));
+    }
+
+    #[test]
+    fn test_input_files() {
+        let answers = AnswerRegistry::default();
+        assert_eq!(vec!["input", "test_input"], input_files(2025, 1, &answers));
+        assert_eq!(
+            vec!["input", "test_input_1", "test_input_2"],
+            input_files(2025, 11, &answers)
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(Status::Pass, check("3", Some("3")));
+        assert_eq!(Status::Fail, check("4", Some("3")));
+        assert_eq!(Status::Unknown, check("4", None));
+    }
+}
@@ -0,0 +1,77 @@
+//! Local stand-in for the Advent of Code site, so tests never touch the real one.
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use tiny_http::{Response, Server};
+
+/// A request received by the stand-in.
+#[derive(Clone, Debug)]
+pub struct Recorded {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Recorded {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(field, _)| field.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// HTTP server on a free local port, answering every request with `respond` and recording it.
+pub struct MockServer {
+    pub url: String,
+    server: Arc<Server>,
+    requests: Arc<Mutex<Vec<Recorded>>>,
+}
+
+impl MockServer {
+    pub fn start(respond: impl Fn(&Recorded) -> (u16, String) + Send + 'static) -> Self {
+        let server = Arc::new(Server::http("127.0.0.1:0").expect("Cannot start mock server"));
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let (incoming, recorded) = (Arc::clone(&server), Arc::clone(&requests));
+        thread::spawn(move || {
+            for mut request in incoming.incoming_requests() {
+                let mut body = String::new();
+                let _ = request.as_reader().read_to_string(&mut body);
+                let received = Recorded {
+                    method: request.method().to_string(),
+                    path: request.url().to_string(),
+                    headers: request
+                        .headers()
+                        .iter()
+                        .map(|header| (header.field.to_string(), header.value.to_string()))
+                        .collect(),
+                    body,
+                };
+
+                let (status, content) = respond(&received);
+                recorded.lock().unwrap().push(received);
+                let _ = request.respond(Response::from_string(content).with_status_code(status));
+            }
+        });
+
+        MockServer {
+            url,
+            server,
+            requests,
+        }
+    }
+
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+    }
+}
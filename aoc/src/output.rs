@@ -0,0 +1,178 @@
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+/// How `run` and `verify` print their results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, clap::ValueEnum)]
+pub enum Format {
+    /// One line per part, for humans
+    #[default]
+    Text,
+    /// A JSON array of all the records, printed at the end
+    Json,
+    /// One JSON record per line, as soon as the part is solved
+    Ndjson,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// Solved by `run`, which doesn't check the answer.
+    Solved,
+    Pass,
+    Fail,
+    /// Solved, but the registry has no answer to check it against.
+    Unknown,
+    Error,
+    MissingInput,
+    /// The day isn't registered in the runner's `solver()`.
+    NotSolved,
+}
+
+/// Result of a part of a day on an input.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Input file name, `<stdin>` for the standard input.
+    pub input: String,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    #[serde(rename = "elapsed_ms", serialize_with = "millis")]
+    pub elapsed: Option<Duration>,
+    pub error: Option<String>,
+}
+
+fn millis<S: Serializer>(elapsed: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+    elapsed
+        .map(|elapsed| elapsed.as_secs_f64() * 1000.)
+        .serialize(serializer)
+}
+
+impl Record {
+    pub fn new(year: u16, day: u8, part: u8, input: &str, status: Status) -> Self {
+        Record {
+            year,
+            day,
+            part,
+            input: input.to_string(),
+            status,
+            answer: None,
+            expected: None,
+            elapsed: None,
+            error: None,
+        }
+    }
+
+    /// The line printed in the text format.
+    pub fn text(&self) -> String {
+        let Record {
+            day, part, input, ..
+        } = self;
+        let label = format!("day {day:02} part {part} \"{input}\"");
+        let answer = self.answer.as_deref().unwrap_or_default();
+        match self.status {
+            Status::Solved => format!(
+                "{label}: {answer} ({:.2?})",
+                self.elapsed.unwrap_or_default()
+            ),
+            Status::Pass => format!("{label}: pass ({answer})"),
+            Status::Fail => format!(
+                "{label}: FAIL, got {answer}, expected {}",
+                self.expected.as_deref().unwrap_or_default()
+            ),
+            Status::Unknown => format!("{label}: unknown ({answer})"),
+            Status::Error => format!(
+                "day {day:02} part {part}: error: {}",
+                self.error.as_deref().unwrap_or_default()
+            ),
+            Status::MissingInput => format!("{label}: missing input file"),
+            Status::NotSolved => {
+                format!("day {day:02} part {part}: not solved yet, no arm in `solver()`")
+            }
+        }
+    }
+}
+
+/// Prints records in a format, errors of the text format going to stderr.
+pub struct Printer {
+    format: Format,
+    records: Vec<Record>,
+}
+
+impl Printer {
+    pub fn new(format: Format) -> Self {
+        Printer {
+            format,
+            records: Vec::new(),
+        }
+    }
+
+    pub fn print(&mut self, record: Record) {
+        match self.format {
+            Format::Text if record.status == Status::Error => eprintln!("{}", record.text()),
+            Format::Text => println!("{}", record.text()),
+            Format::Ndjson => println!(
+                "{}",
+                serde_json::to_string(&record).expect("Records are valid JSON")
+            ),
+            Format::Json => self.records.push(record),
+        }
+    }
+
+    /// Prints the records held back until the end.
+    pub fn finish(self) {
+        if self.format == Format::Json {
+            let json = serde_json::to_string_pretty(&self.records).expect("Records are valid JSON");
+            println!("{json}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solved() -> Record {
+        Record {
+            answer: Some("3".to_string()),
+            elapsed: Some(Duration::from_micros(1500)),
+            ..Record::new(2025, 1, 2, "test_input", Status::Solved)
+        }
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!("day 01 part 2 \"test_input\": 3 (1.50ms)", solved().text());
+        let fail = Record {
+            status: Status::Fail,
+            expected: Some("6".to_string()),
+            ..solved()
+        };
+        assert_eq!(
+            "day 01 part 2 \"test_input\": FAIL, got 3, expected 6",
+            fail.text()
+        );
+        assert_eq!(
+            "day 12 part 1: not solved yet, no arm in `solver()`",
+            Record::new(2025, 12, 1, "input", Status::NotSolved).text()
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            r#"{"year":2025,"day":1,"part":2,"input":"test_input","status":"solved","#.to_string()
+                + r#""answer":"3","expected":null,"elapsed_ms":1.5,"error":null}"#,
+            serde_json::to_string(&solved()).unwrap()
+        );
+        let missing = Record::new(2025, 1, 1, "input", Status::MissingInput);
+        assert!(
+            serde_json::to_string(&missing)
+                .unwrap()
+                .contains(r#""status":"missing_input""#)
+        );
+    }
+}
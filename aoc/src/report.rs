@@ -0,0 +1,236 @@
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc_core::{Overrides, Params, ParseError, Solution};
+
+use crate::allocations::{self, Allocations};
+
+/// Start and end markers of the section of `README.md` holding the report.
+pub const README_START: &str = "<!-- timings:start -->";
+pub const README_END: &str = "<!-- timings:end -->";
+
+/// Median time of each step of a day.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+fn median(mut samples: Vec<Duration>) -> Duration {
+    samples.sort();
+    samples.get(samples.len() / 2).copied().unwrap_or_default()
+}
+
+/// Times parsing `input` and both parts over `iterations` runs.
+pub fn time<S: Solution>(
+    input: &str,
+    overrides: &Overrides,
+    iterations: u32,
+) -> Result<Timings, ParseError> {
+    let params = S::Params::with(overrides)?;
+    let (mut parse, mut part1, mut part2) = (Vec::new(), Vec::new(), Vec::new());
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let input = black_box(S::parse_with(black_box(input), &params)?);
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&input));
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&input));
+        part2.push(start.elapsed());
+    }
+
+    Ok(Timings {
+        parse: median(parse),
+        part1: median(part1),
+        part2: median(part2),
+    })
+}
+
+/// Allocations of each step of a day.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StepAllocations {
+    pub parse: Allocations,
+    pub part1: Allocations,
+    pub part2: Allocations,
+}
+
+/// Counts the allocations of parsing `input` and of both parts.
+pub fn allocations<S: Solution>(
+    input: &str,
+    overrides: &Overrides,
+) -> Result<StepAllocations, ParseError> {
+    let params = S::Params::with(overrides)?;
+    let (input, parse) = allocations::measure(|| S::parse_with(input, &params));
+    let input = input?;
+    let (_, part1) = allocations::measure(|| black_box(S::part1(&input)));
+    let (_, part2) = allocations::measure(|| black_box(S::part2(&input)));
+
+    Ok(StepAllocations {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+fn size(bytes: usize) -> String {
+    match bytes {
+        ..1024 => format!("{bytes} B"),
+        1024..1_048_576 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+/// Markdown table of the allocations of each step of each day.
+pub fn allocation_table(days: &[(u8, StepAllocations)]) -> String {
+    let mut table = String::from(
+        "| Day | Step | Allocations | Allocated | Peak |\n\
+         |-----|------|------------:|----------:|-----:|\n",
+    );
+    for (day, steps) in days {
+        for (step, allocations) in [
+            ("parse", steps.parse),
+            ("part 1", steps.part1),
+            ("part 2", steps.part2),
+        ] {
+            let _ = writeln!(
+                table,
+                "| {day:02} | {step} | {} | {} | {} |",
+                allocations.count,
+                size(allocations.bytes),
+                size(allocations.peak)
+            );
+        }
+    }
+    table
+}
+
+/// Markdown table of the timings of each day, with the total of all days.
+pub fn table(days: &[(u8, Timings)], iterations: u32) -> String {
+    let mut table = format!(
+        "Median of {iterations} runs on the puzzle input.\n\n\
+         | Day | Parse | Part 1 | Part 2 | Total |\n\
+         |-----|------:|-------:|-------:|------:|\n"
+    );
+
+    let mut total = Duration::ZERO;
+    for (day, timings) in days {
+        total += timings.total();
+        let _ = writeln!(
+            table,
+            "| {day:02} | {:.2?} | {:.2?} | {:.2?} | {:.2?} |",
+            timings.parse,
+            timings.part1,
+            timings.part2,
+            timings.total()
+        );
+    }
+    let _ = writeln!(table, "| **All** | | | | **{total:.2?}** |");
+    table
+}
+
+/// `readme` with the content between the markers replaced by `table`, `None` without markers.
+pub fn update_readme(readme: &str, table: &str) -> Option<String> {
+    let (before, rest) = readme.split_once(README_START)?;
+    let (_, after) = rest.split_once(README_END)?;
+    Some(format!(
+        "{before}{README_START}\n{table}{README_END}{after}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(ms(2), median(vec![ms(3), ms(1), ms(2)]));
+        assert_eq!(Duration::ZERO, median(Vec::new()));
+    }
+
+    #[test]
+    fn test_time() {
+        let input = std::fs::read_to_string(crate::day_dir(2025, 1).join("test_input")).unwrap();
+        let overrides = Overrides::new();
+        assert!(time::<y2025_day_01::Day01>(&input, &overrides, 3).is_ok());
+        assert!(time::<y2025_day_01::Day01>("X5\n", &overrides, 3).is_err());
+    }
+
+    #[test]
+    fn test_table() {
+        let timings = Timings {
+            parse: ms(1),
+            part1: ms(2),
+            part2: ms(3),
+        };
+        assert_eq!(
+            "Median of 5 runs on the puzzle input.\n\n\
+             | Day | Parse | Part 1 | Part 2 | Total |\n\
+             |-----|------:|-------:|-------:|------:|\n\
+             | 01 | 1.00ms | 2.00ms | 3.00ms | 6.00ms |\n\
+             | 09 | 1.00ms | 2.00ms | 3.00ms | 6.00ms |\n\
+             | **All** | | | | **12.00ms** |\n",
+            table(&[(1, timings), (9, timings)], 5)
+        );
+    }
+
+    #[test]
+    fn test_allocations() {
+        let input = std::fs::read_to_string(crate::day_dir(2025, 1).join("test_input")).unwrap();
+        let overrides = Overrides::new();
+        assert!(allocations::<y2025_day_01::Day01>(&input, &overrides).is_ok());
+        assert!(allocations::<y2025_day_01::Day01>("X5\n", &overrides).is_err());
+    }
+
+    #[test]
+    fn test_allocation_table() {
+        let steps = StepAllocations {
+            parse: Allocations {
+                count: 3,
+                bytes: 2048,
+                peak: 1000,
+            },
+            part1: Allocations::default(),
+            part2: Allocations {
+                count: 1,
+                bytes: 3 << 20,
+                peak: 3 << 20,
+            },
+        };
+        assert_eq!(
+            "| Day | Step | Allocations | Allocated | Peak |\n\
+             |-----|------|------------:|----------:|-----:|\n\
+             | 04 | parse | 3 | 2.0 KiB | 1000 B |\n\
+             | 04 | part 1 | 0 | 0 B | 0 B |\n\
+             | 04 | part 2 | 1 | 3.0 MiB | 3.0 MiB |\n",
+            allocation_table(&[(4, steps)])
+        );
+    }
+
+    #[test]
+    fn test_update_readme() {
+        let readme = format!("# Title\n{README_START}\nold\n{README_END}\n## Next\n");
+        assert_eq!(
+            Some(format!(
+                "# Title\n{README_START}\nnew\n{README_END}\n## Next\n"
+            )),
+            update_readme(&readme, "new\n")
+        );
+        assert_eq!(None, update_readme("# Title\n", "new\n"));
+    }
+}
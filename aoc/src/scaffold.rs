@@ -0,0 +1,284 @@
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use toml_edit::{Array, DocumentMut, InlineTable};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+const TESTS_RS: &str = include_str!("../templates/solution.rs.tmpl");
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &format!("{day:02}"))
+}
+
+fn invalid_data(err: impl std::fmt::Display) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, err.to_string())
+}
+
+/// Adds `member` to the `members` of a workspace manifest, keeping them sorted and one per
+/// line if they were.
+fn add_member(manifest: &str, member: &str) -> io::Result<String> {
+    let mut doc: DocumentMut = manifest.parse().map_err(invalid_data)?;
+    let members = doc["workspace"]["members"]
+        .or_insert(toml_edit::value(Array::new()))
+        .as_array_mut()
+        .ok_or_else(|| invalid_data("`workspace.members` is not an array"))?;
+
+    let mut names: Vec<String> = members
+        .iter()
+        .filter_map(|name| name.as_str().map(String::from))
+        .chain([member.to_string()])
+        .collect();
+    names.sort();
+    names.dedup();
+    let one_per_line = members.iter().any(|name| {
+        name.decor()
+            .prefix()
+            .and_then(|prefix| prefix.as_str())
+            .is_some_and(|prefix| prefix.contains('\n'))
+    });
+
+    *members = names.iter().collect();
+    if one_per_line {
+        for name in members.iter_mut() {
+            name.decor_mut().set_prefix("\n    ");
+        }
+        members.set_trailing_comma(true);
+        members.set_trailing("\n");
+    }
+
+    Ok(doc.to_string())
+}
+
+/// Adds the crate of a day to the dependencies of the runner's manifest, keeping them
+/// sorted if they were.
+fn add_dependency(manifest: &str, year: u16, day: u8) -> io::Result<String> {
+    let mut doc: DocumentMut = manifest.parse().map_err(invalid_data)?;
+    let dependencies = doc["dependencies"]
+        .or_insert(toml_edit::table())
+        .as_table_mut()
+        .ok_or_else(|| invalid_data("`dependencies` is not a table"))?;
+
+    let sorted = dependencies
+        .iter()
+        .zip(dependencies.iter().skip(1))
+        .all(|((a, _), (b, _))| a <= b);
+    let mut path = InlineTable::new();
+    path.insert("path", format!("../{year}/day_{day:02}").into());
+    dependencies.insert(&format!("y{year}_day_{day:02}"), toml_edit::value(path));
+    if sorted {
+        dependencies.sort_values();
+    }
+
+    Ok(doc.to_string())
+}
+
+/// Adds the arm of a day to the `match` of `solver()` in the runner's `main.rs`, in order
+/// of year and day.
+fn add_solver(main_rs: &str, year: u16, day: u8) -> io::Result<String> {
+    let missing = || invalid_data("no `match` of `solver()` ending in `_ => return None,`");
+    // arms look like `(2025, 1) => Solver::of::<y2025_day_01::Day01>(),`
+    let key = |arm: &str| -> Option<(u16, u8)> {
+        let (year, day) = arm
+            .trim()
+            .strip_prefix('(')?
+            .split_once(')')?
+            .0
+            .split_once(',')?;
+        Some((year.trim().parse().ok()?, day.trim().parse().ok()?))
+    };
+
+    let mut lines: Vec<&str> = main_rs.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("fn solver("))
+        .ok_or_else(missing)?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "_ => return None,")
+            .ok_or_else(missing)?;
+    let at = (start..end)
+        .find(|&idx| key(lines[idx]).is_some_and(|key| key > (year, day)))
+        .unwrap_or(end);
+
+    let indent = &lines[end][..lines[end].len() - lines[end].trim_start().len()];
+    let arm =
+        format!("{indent}({year}, {day}) => Solver::of::<y{year}_day_{day:02}::Day{day:02}>(),");
+    lines.insert(at, &arm);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates the crate of `day` in the directory of `year` under `root`, adds it to the
+/// workspace and registers it in the runner. Returns the directory of the crate.
+pub fn new_day(root: &Path, year: u16, day: u8) -> io::Result<PathBuf> {
+    let member = format!("{year}/day_{day:02}");
+    let day_dir = root.join(&member);
+    if day_dir.exists() {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} already exists", day_dir.display()),
+        ));
+    }
+
+    // everything is checked before anything is written
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = add_member(&fs::read_to_string(&manifest_path)?, &member)?;
+    let runner_manifest_path = root.join("aoc/Cargo.toml");
+    let runner_manifest = add_dependency(&fs::read_to_string(&runner_manifest_path)?, year, day)?;
+    let runner_main_path = root.join("aoc/src/main.rs");
+    let runner_main = add_solver(&fs::read_to_string(&runner_main_path)?, year, day)?;
+
+    fs::create_dir_all(day_dir.join("src"))?;
+    fs::create_dir_all(day_dir.join("tests"))?;
+    fs::write(day_dir.join("Cargo.toml"), render(CARGO_TOML, year, day))?;
+    fs::write(day_dir.join("src/lib.rs"), render(LIB_RS, year, day))?;
+    fs::write(day_dir.join("src/main.rs"), render(MAIN_RS, year, day))?;
+    fs::write(
+        day_dir.join("tests/solution.rs"),
+        render(TESTS_RS, year, day),
+    )?;
+    fs::write(day_dir.join("input"), "")?;
+    fs::write(day_dir.join("test_input"), "")?;
+    fs::write(manifest_path, manifest)?;
+    fs::write(runner_manifest_path, runner_manifest)?;
+    fs::write(runner_main_path, runner_main)?;
+
+    Ok(day_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_member() {
+        let manifest =
+            "[workspace]\nresolver = \"3\"\nmembers = [ \"aoc\", \"day_02\",\"day_01\"]\n";
+        assert_eq!(
+            "[workspace]\nresolver = \"3\"\nmembers = [\"aoc\", \"day_01\", \"day_02\", \"day_03\"]\n",
+            add_member(manifest, "day_03").unwrap()
+        );
+        assert_eq!(
+            "[workspace]\nmembers = [\"day_01\"]\n",
+            add_member("[workspace]\n", "day_01").unwrap()
+        );
+        assert_eq!(
+            "[workspace]\nmembers = [\n    \"2025/day_01\",\n    \"2026/day_01\",\n    \"aoc\",\n]\n",
+            add_member(
+                "[workspace]\nmembers = [\n    \"2025/day_01\",\n    \"aoc\",\n]\n",
+                "2026/day_01"
+            )
+            .unwrap()
+        );
+        assert!(add_member("[workspace]\nmembers = 1\n", "day_01").is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let manifest = render(CARGO_TOML, 2026, 3);
+        assert!(manifest.contains("name = \"y2026_day_03\""));
+        assert!(manifest.contains("aoc_core.workspace = true"));
+        let lib = render(LIB_RS, 2026, 3);
+        assert!(lib.contains("impl Solution for Day03 {"));
+        // a new day runs and verifies before it's solved
+        assert!(!lib.contains("todo!"));
+        let tests = render(TESTS_RS, 2026, 3);
+        assert!(tests.contains("use y2026_day_03::{Day03, TEST_INPUT};"));
+        // the placeholder answers aren't checked until they're filled in
+        assert_eq!(
+            2,
+            tests
+                .matches("#[ignore = \"fill in the example answer")
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest = "[package]\nname = \"aoc\"\n\n[dependencies]\nclap = \"4\"\n\
+                        y2025_day_01 = { path = \"../2025/day_01\" }\n\
+                        y2025_day_03 = { path = \"../2025/day_03\" }\n\n[dev-dependencies]\n";
+        assert_eq!(
+            "[package]\nname = \"aoc\"\n\n[dependencies]\nclap = \"4\"\n\
+             y2025_day_01 = { path = \"../2025/day_01\" }\n\
+             y2025_day_02 = { path = \"../2025/day_02\" }\n\
+             y2025_day_03 = { path = \"../2025/day_03\" }\n\n[dev-dependencies]\n",
+            add_dependency(manifest, 2025, 2).unwrap()
+        );
+        assert!(add_dependency("dependencies = 1\n", 2025, 2).is_err());
+    }
+
+    #[test]
+    fn test_add_solver() {
+        let main_rs = "fn solver(year: u16, day: u8) -> Option<Solver> {\n    \
+                       let solver = match (year, day) {\n        \
+                       (2025, 1) => Solver::of::<y2025_day_01::Day01>(),\n        \
+                       (2025, 10) => Solver::of::<y2025_day_10::Day10>(),\n        \
+                       _ => return None,\n    };\n}\n";
+        let with_day = |year, day| add_solver(main_rs, year, day).unwrap();
+        assert!(with_day(2025, 2).contains(
+            "(2025, 1) => Solver::of::<y2025_day_01::Day01>(),\n        \
+             (2025, 2) => Solver::of::<y2025_day_02::Day02>(),\n        \
+             (2025, 10) =>"
+        ));
+        assert!(with_day(2026, 1).contains(
+            "(2025, 10) => Solver::of::<y2025_day_10::Day10>(),\n        \
+             (2026, 1) => Solver::of::<y2026_day_01::Day01>(),\n        \
+             _ => return None,"
+        ));
+        assert!(add_solver("fn main() {}\n", 2025, 2).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(
+            root.path().join("Cargo.toml"),
+            "[workspace]\nresolver = \"3\"\nmembers = [\"2025/day_01\", \"aoc\"]\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.path().join("aoc/src")).unwrap();
+        fs::write(root.path().join("aoc/Cargo.toml"), "[dependencies]\n").unwrap();
+        let main_rs = "fn solver(year: u16, day: u8) -> Option<Solver> {\n    \
+                       let solver = match (year, day) {\n        _ => return None,\n    };\n}\n";
+        fs::write(root.path().join("aoc/src/main.rs"), main_rs).unwrap();
+
+        let day_dir = new_day(root.path(), 2026, 1).unwrap();
+        assert_eq!(root.path().join("2026/day_01"), day_dir);
+        for file in [
+            "Cargo.toml",
+            "src/lib.rs",
+            "src/main.rs",
+            "input",
+            "test_input",
+        ] {
+            assert!(day_dir.join(file).exists(), "missing {file}");
+        }
+        new_day(root.path(), 2026, 2).unwrap();
+        assert_eq!(
+            "[workspace]\nresolver = \"3\"\n\
+             members = [\"2025/day_01\", \"2026/day_01\", \"2026/day_02\", \"aoc\"]\n",
+            fs::read_to_string(root.path().join("Cargo.toml")).unwrap()
+        );
+
+        assert_eq!(
+            "[dependencies]\ny2026_day_01 = { path = \"../2026/day_01\" }\n\
+             y2026_day_02 = { path = \"../2026/day_02\" }\n",
+            fs::read_to_string(root.path().join("aoc/Cargo.toml")).unwrap()
+        );
+        let main_rs = fs::read_to_string(root.path().join("aoc/src/main.rs")).unwrap();
+        assert!(main_rs.contains("(2026, 2) => Solver::of::<y2026_day_02::Day02>(),"));
+
+        let err = new_day(root.path(), 2026, 1).unwrap_err();
+        assert_eq!(ErrorKind::AlreadyExists, err.kind());
+        // nothing is created if the runner can't be updated
+        fs::write(root.path().join("aoc/src/main.rs"), "fn main() {}\n").unwrap();
+        assert!(new_day(root.path(), 2026, 3).is_err());
+        assert!(!root.path().join("2026/day_03").exists());
+    }
+}
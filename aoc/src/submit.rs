@@ -0,0 +1,364 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::answers::{self, AnswerRegistry};
+use crate::client::{Client, ClientError};
+
+/// Only answers of the puzzle input are submitted.
+const INPUT: &str = "input";
+
+/// The site's verdict on a submitted answer.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    /// Rejected answer, with the delay before the next attempt.
+    Wrong {
+        hint: Option<Hint>,
+        wait: Duration,
+    },
+    /// Submitted too soon after the previous answer, nothing was checked.
+    TooSoon(Duration),
+    AlreadySolved,
+    /// A page that doesn't look like any verdict, as text.
+    Unknown(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong { hint, wait } => {
+                write!(f, "wrong")?;
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", too high")?,
+                    Some(Hint::TooLow) => write!(f, ", too low")?,
+                    None => {}
+                }
+                write!(f, ", next attempt in {wait:?}")
+            }
+            Verdict::TooSoon(wait) => write!(f, "not checked, next attempt in {wait:?}"),
+            Verdict::AlreadySolved => write!(f, "not checked, the part is already solved"),
+            Verdict::Unknown(text) => write!(f, "unknown response: {text}"),
+        }
+    }
+}
+
+/// Text of the `<article>` holding the verdict, without markup.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses durations like `4m 32s`.
+fn parse_duration(text: &str) -> Option<Duration> {
+    let mut secs = 0u64;
+    for token in text.split_whitespace() {
+        let (value, unit) = [("h", 3600), ("m", 60), ("s", 1)]
+            .into_iter()
+            .find_map(|(suffix, unit)| Some((token.strip_suffix(suffix)?, unit)))?;
+        secs = value
+            .parse::<u64>()
+            .ok()?
+            .checked_mul(unit)
+            .and_then(|value| secs.checked_add(value))?;
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Delay after a wrong answer, as in `please wait 5 minutes before trying again`.
+fn wait_after_wrong(text: &str) -> Duration {
+    let wait = text
+        .to_lowercase()
+        .split_once("please wait ")
+        .and_then(|(_, rest)| {
+            let (count, unit) = rest.split_once(' ')?;
+            let count = match count {
+                "one" => 1,
+                count => count.parse().ok()?,
+            };
+            let unit = match unit {
+                unit if unit.starts_with("second") => 1,
+                unit if unit.starts_with("minute") => 60,
+                unit if unit.starts_with("hour") => 3600,
+                _ => return None,
+            };
+            Some(Duration::from_secs(count * unit))
+        });
+
+    wait.unwrap_or(Duration::from_secs(60))
+}
+
+pub fn classify(page: &str) -> Verdict {
+    let text = article_text(page);
+
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Verdict::Wrong {
+            hint,
+            wait: wait_after_wrong(&text),
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(left, _)| parse_duration(left))
+            .unwrap_or(Duration::from_secs(60));
+        Verdict::TooSoon(wait)
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown(text)
+    }
+}
+
+/// Local state of the submissions of a year.
+pub struct Store {
+    pub year: u16,
+    /// Answer registry, see `answers.rs`.
+    pub answers: PathBuf,
+    /// Earliest time of the next submission, as Unix seconds.
+    pub throttle: PathBuf,
+}
+
+impl Store {
+    fn not_before(&self) -> u64 {
+        fs::read_to_string(&self.throttle)
+            .ok()
+            .and_then(|secs| secs.trim().parse().ok())
+            .unwrap_or(0)
+    }
+
+    fn set_not_before(&self, secs: u64) -> io::Result<()> {
+        fs::write(&self.throttle, secs.to_string())
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Solved { expected: String },
+    KnownWrong,
+    Throttled(Duration),
+    Client(ClientError),
+    Io(io::Error),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Solved { expected } => {
+                write!(f, "already solved, the registry has {expected}")
+            }
+            SubmitError::KnownWrong => write!(f, "already rejected by the site, not submitted"),
+            SubmitError::Throttled(wait) => write!(f, "next attempt in {wait:?}, not submitted"),
+            SubmitError::Client(err) => write!(f, "{err}"),
+            SubmitError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<ClientError> for SubmitError {
+    fn from(err: ClientError) -> Self {
+        SubmitError::Client(err)
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(err: io::Error) -> Self {
+        SubmitError::Io(err)
+    }
+}
+
+/// Submits the answer of a part unless the store tells it's pointless, and records the
+/// verdict. `now` is in Unix seconds.
+pub fn submit(
+    client: &Client,
+    store: &Store,
+    day: u8,
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> Result<Verdict, SubmitError> {
+    let registry = AnswerRegistry::load(&store.answers)?;
+    if let Some(expected) = registry.expected(day, INPUT, part) {
+        return Err(SubmitError::Solved {
+            expected: expected.to_string(),
+        });
+    }
+    if registry.is_wrong(day, INPUT, part, answer) {
+        return Err(SubmitError::KnownWrong);
+    }
+    let not_before = store.not_before();
+    if now < not_before {
+        return Err(SubmitError::Throttled(Duration::from_secs(
+            not_before - now,
+        )));
+    }
+
+    let verdict = classify(&client.submit(store.year, day, part, answer)?);
+    match &verdict {
+        Verdict::Correct => answers::record(&store.answers, day, INPUT, part, answer, true)?,
+        Verdict::Wrong { wait, .. } => {
+            answers::record(&store.answers, day, INPUT, part, answer, false)?;
+            store.set_not_before(now + wait.as_secs())?;
+        }
+        Verdict::TooSoon(wait) => store.set_not_before(now + wait.as_secs())?,
+        Verdict::AlreadySolved | Verdict::Unknown(_) => {}
+    }
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockServer;
+
+    fn page(text: &str) -> String {
+        format!("<html><body><main>\n<article><p>{text}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(
+            Verdict::Correct,
+            classify(&page(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            ))
+        );
+        assert_eq!(
+            Verdict::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Duration::from_secs(60)
+            },
+            classify(&page(
+                "That's not the right answer; your answer is too high. If you're stuck, \
+                 make sure you're using the full input data. Please wait one minute before \
+                 trying again. <a href=\"/2025/day/1\">[Return to Day 1]</a>"
+            ))
+        );
+        assert_eq!(
+            Verdict::Wrong {
+                hint: None,
+                wait: Duration::from_secs(300)
+            },
+            classify(&page(
+                "That's not the right answer. Because you have guessed incorrectly 4 times \
+                 on this puzzle, please wait 5 minutes before trying again."
+            ))
+        );
+        assert_eq!(
+            Verdict::TooSoon(Duration::from_secs(92)),
+            classify(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again. You have 1m 32s left to wait."
+            ))
+        );
+        assert_eq!(
+            Verdict::AlreadySolved,
+            classify(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ))
+        );
+        assert_eq!(
+            Verdict::Unknown("Maintenance".to_string()),
+            classify(&page("<b>Maintenance</b>"))
+        );
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(Some(Duration::from_secs(32)), parse_duration("32s"));
+        assert_eq!(Some(Duration::from_secs(272)), parse_duration("4m 32s"));
+        assert_eq!(None, parse_duration("soon"));
+        assert_eq!(None, parse_duration("5"));
+        // scraped text may end in any character
+        assert_eq!(None, parse_duration("5é"));
+        assert_eq!(None, parse_duration("→"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = MockServer::start(|request| match request.body.as_str() {
+            "level=1&answer=42" => (200, page("That's the right answer!")),
+            _ => (
+                200,
+                page(
+                    "That's not the right answer; your answer is too low. \
+                     Please wait one minute before trying again.",
+                ),
+            ),
+        });
+        let client = Client::new(&server.url, "token");
+        let dir = tempfile::tempdir().unwrap();
+        let store = Store {
+            year: 2025,
+            answers: dir.path().join("answers.toml"),
+            throttle: dir.path().join("throttle"),
+        };
+
+        let verdict = submit(&client, &store, 1, 1, "41", 1000).unwrap();
+        assert!(matches!(
+            verdict,
+            Verdict::Wrong {
+                hint: Some(Hint::TooLow),
+                ..
+            }
+        ));
+        assert!(matches!(
+            submit(&client, &store, 1, 1, "41", 2000),
+            Err(SubmitError::KnownWrong)
+        ));
+        assert!(matches!(
+            submit(&client, &store, 1, 1, "42", 1030),
+            Err(SubmitError::Throttled(wait)) if wait == Duration::from_secs(30)
+        ));
+        assert_eq!(
+            Verdict::Correct,
+            submit(&client, &store, 1, 1, "42", 1060).unwrap()
+        );
+        assert!(matches!(
+            submit(&client, &store, 1, 1, "42", 2000),
+            Err(SubmitError::Solved { expected }) if expected == "42"
+        ));
+
+        let requests = server.requests();
+        assert_eq!(2, requests.len());
+        assert_eq!("POST", requests[0].method);
+        assert_eq!("/2025/day/1/answer", requests[0].path);
+        let registry = AnswerRegistry::load(&store.answers).unwrap();
+        assert_eq!(Some("42"), registry.expected(1, "input", 1));
+        assert!(registry.is_wrong(1, "input", 1, "41"));
+    }
+}
@@ -0,0 +1,118 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+/// Quiet time ending the burst of events of a single save.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Whether a change of `path` can change the answers of the day: its sources, its inputs and
+/// the answer registry of the year. Hidden files and editor backups are ignored.
+pub fn is_relevant(year_dir: &Path, day_dir: &Path, path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    if name.starts_with('.') || name.ends_with('~') {
+        return false;
+    }
+
+    match path.parent() {
+        _ if path.starts_with(day_dir.join("src")) => true,
+        Some(parent) if parent == day_dir => name == "input" || name.starts_with("test_input"),
+        Some(parent) if parent == year_dir => name == "answers.toml",
+        _ => false,
+    }
+}
+
+/// Paths changed by an event, reading a file is not a change.
+fn changed_paths(event: notify::Result<Event>) -> Vec<PathBuf> {
+    match event {
+        Ok(event) => match event.kind {
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_) => event.paths,
+            _ => Vec::new(),
+        },
+        Err(err) => {
+            eprintln!("warning: {err}");
+            Vec::new()
+        }
+    }
+}
+
+/// Rebuilds the runner and verifies the day, which solves the examples and the puzzle input
+/// and shows the answers differing from the registry.
+fn verify(root: &Path, year: u16, day: u8, release: bool) {
+    let mut cargo = Command::new("cargo");
+    cargo
+        .current_dir(root)
+        .args(["run", "--quiet", "-p", "aoc"]);
+    if release {
+        cargo.arg("--release");
+    }
+    cargo.args(["--", "verify", "--year", &year.to_string()]);
+    cargo.args(["--day", &day.to_string()]);
+
+    if let Err(err) = cargo.status() {
+        eprintln!("error: cannot run cargo: {err}");
+    }
+}
+
+/// Verifies the day, then again after every change of its sources or inputs, until the
+/// process is interrupted.
+pub fn watch(root: &Path, year: u16, day: u8, release: bool) -> notify::Result<()> {
+    let year_dir = root.join(year.to_string());
+    let day_dir = year_dir.join(format!("day_{day:02}"));
+    let (sender, events) = mpsc::channel::<notify::Result<Event>>();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    watcher.watch(&day_dir, RecursiveMode::Recursive)?;
+    watcher.watch(&year_dir, RecursiveMode::NonRecursive)?;
+
+    verify(root, year, day, release);
+    println!("watching {} for changes", day_dir.display());
+    while let Ok(event) = events.recv() {
+        let mut changed = changed_paths(event);
+        while let Ok(event) = events.recv_timeout(DEBOUNCE) {
+            changed.extend(changed_paths(event));
+        }
+        changed.retain(|path| is_relevant(&year_dir, &day_dir, path));
+        changed.sort();
+        changed.dedup();
+        if changed.is_empty() {
+            continue;
+        }
+
+        println!();
+        for path in &changed {
+            let path = path.strip_prefix(root).unwrap_or(path);
+            println!("{} changed", path.display());
+        }
+        verify(root, year, day, release);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_relevant() {
+        let year_dir = Path::new("/aoc/2025");
+        let day_dir = year_dir.join("day_03");
+        let relevant = |path: &str| is_relevant(year_dir, &day_dir, &year_dir.join(path));
+
+        assert!(relevant("day_03/src/lib.rs"));
+        assert!(relevant("day_03/src/bin/extra.rs"));
+        assert!(relevant("day_03/input"));
+        assert!(relevant("day_03/test_input_2"));
+        assert!(relevant("answers.toml"));
+
+        assert!(!relevant("day_03/src/.lib.rs.swp"));
+        assert!(!relevant("day_03/src/lib.rs~"));
+        assert!(!relevant("day_03/notes.md"));
+        assert!(!relevant("day_03/tests/input"));
+        assert!(!relevant("day_04/input"));
+        assert!(!relevant("target/debug/aoc"));
+    }
+}
@@ -0,0 +1,269 @@
+use std::ops::{Add, Mul, Range, Sub};
+
+use crate::Grid;
+
+/// Cell values that can be summed and weighted, `Default` being zero.
+pub trait Number:
+    Copy + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+}
+
+impl<T> Number for T where T: Copy + Default + Add<Output = T> + Sub<Output = T> + Mul<Output = T> {}
+
+/// Values of the cells outside the grid seen by a kernel.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Border {
+    /// Zero everywhere outside.
+    #[default]
+    Zero,
+    /// The nearest cell on the edge.
+    Clamp,
+    /// The grid repeats, leaving on one side enters on the other.
+    Wrap,
+}
+
+impl Border {
+    /// Index of `pos` along an axis of `len` cells, `None` for a zero.
+    fn index(self, pos: isize, len: usize) -> Option<usize> {
+        let len = len as isize;
+        let pos = match self {
+            Border::Zero if !(0..len).contains(&pos) => return None,
+            Border::Zero => pos,
+            Border::Clamp => pos.clamp(0, len - 1),
+            Border::Wrap => pos.rem_euclid(len),
+        };
+        Some(pos as usize)
+    }
+
+    fn sample<T: Number>(self, grid: &Grid<T>, x: isize, y: isize) -> T {
+        match (self.index(x, grid.width()), self.index(y, grid.height())) {
+            (Some(x), Some(y)) => grid[(x, y)],
+            _ => T::default(),
+        }
+    }
+}
+
+/// Sum of the `(2 * radius + 1)²` cells centered on every cell, a window sliding along
+/// every row and then one of whole rows sliding down.
+pub fn box_filter<T: Number>(grid: &Grid<T>, radius: usize, border: Border) -> Grid<T> {
+    let (width, height) = (grid.width(), grid.height());
+    let side = 2 * radius + 1;
+
+    let columns: Vec<Option<usize>> = padded(width, radius, border).collect();
+    let mut line = Vec::with_capacity(columns.len());
+    let mut rows = Vec::with_capacity(width * height);
+    for row in grid.rows() {
+        line.clear();
+        line.extend(
+            columns
+                .iter()
+                .map(|x| x.map_or_else(T::default, |x| row[x])),
+        );
+
+        let mut sum = line[..side]
+            .iter()
+            .fold(T::default(), |sum, &cell| sum + cell);
+        rows.push(sum);
+        for (&entering, &leaving) in line[side..].iter().zip(&line) {
+            sum = sum + entering - leaving;
+            rows.push(sum);
+        }
+    }
+    let rows = Grid::from_cells(width, rows);
+
+    let lines: Vec<Option<&[T]>> = padded(height, radius, border)
+        .map(|y| y.map(|y| rows.row(y)))
+        .collect();
+    let mut sum = vec![T::default(); width];
+    let mut out = Vec::with_capacity(width * height);
+    for (y, entering) in lines.iter().enumerate() {
+        if let Some(entering) = entering {
+            sum.iter_mut()
+                .zip(*entering)
+                .for_each(|(sum, &cell)| *sum = *sum + cell);
+        }
+        if let Some(Some(leaving)) = y.checked_sub(side).map(|y| lines[y]) {
+            sum.iter_mut()
+                .zip(leaving)
+                .for_each(|(sum, &cell)| *sum = *sum - cell);
+        }
+        if y + 1 >= side {
+            out.extend_from_slice(&sum);
+        }
+    }
+    Grid::from_cells(width, out)
+}
+
+/// Indices along an axis of `len` cells from `radius` before it to `radius` after it,
+/// `None` for a zero.
+fn padded(len: usize, radius: usize, border: Border) -> impl Iterator<Item = Option<usize>> {
+    (-(radius as isize)..(len + radius) as isize).map(move |pos| border.index(pos, len))
+}
+
+/// Weighted sum around every cell, `kernel` has an odd width and height and is centered on
+/// the cell.
+///
+/// # Panics
+///
+/// If the kernel has an even width or height.
+pub fn convolve<T: Number>(grid: &Grid<T>, kernel: &Grid<T>, border: Border) -> Grid<T> {
+    assert!(
+        kernel.width() % 2 == 1 && kernel.height() % 2 == 1,
+        "a {}x{} kernel has no center",
+        kernel.width(),
+        kernel.height()
+    );
+    let (rx, ry) = (kernel.width() as isize / 2, kernel.height() as isize / 2);
+
+    Grid::from_fn(grid.width(), grid.height(), |x, y| {
+        kernel
+            .iter()
+            .fold(T::default(), |sum, ((kx, ky), &weight)| {
+                let sx = x as isize + kx as isize - rx;
+                let sy = y as isize + ky as isize - ry;
+                sum + weight * border.sample(grid, sx, sy)
+            })
+    })
+}
+
+/// Sums of the cells above and to the left of every corner, to sum any rectangle in 4
+/// lookups.
+#[derive(Clone, Debug)]
+pub struct SummedAreaTable<T> {
+    /// `(width + 1) x (height + 1)` corners, the first row and column are zero.
+    sums: Grid<T>,
+}
+
+impl<T: Number> SummedAreaTable<T> {
+    pub fn new(grid: &Grid<T>) -> Self {
+        let mut sums = Grid::filled(grid.width() + 1, grid.height() + 1, T::default());
+        for ((x, y), &cell) in grid.iter() {
+            sums[(x + 1, y + 1)] = cell + sums[(x, y + 1)] + sums[(x + 1, y)] - sums[(x, y)];
+        }
+        SummedAreaTable { sums }
+    }
+
+    /// Sum of the cells in columns `xs` and rows `ys`, clipped to the grid.
+    pub fn sum(&self, xs: Range<usize>, ys: Range<usize>) -> T {
+        let clip = |range: Range<usize>, len: usize| range.start.min(len)..range.end.min(len);
+        let xs = clip(xs, self.sums.width() - 1);
+        let ys = clip(ys, self.sums.height() - 1);
+        if xs.is_empty() || ys.is_empty() {
+            return T::default();
+        }
+
+        self.sums[(xs.end, ys.end)] + self.sums[(xs.start, ys.start)]
+            - self.sums[(xs.start, ys.end)]
+            - self.sums[(xs.end, ys.start)]
+    }
+
+    /// Sum of the `(2 * radius + 1)²` cells centered on `(x, y)`, zero outside the grid.
+    pub fn window(&self, x: usize, y: usize, radius: usize) -> T {
+        self.sum(
+            x.saturating_sub(radius)..x + radius + 1,
+            y.saturating_sub(radius)..y + radius + 1,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Weighted sum around every cell, one cell at a time.
+    fn naive(grid: &Grid<i64>, kernel: &Grid<i64>, border: Border) -> Grid<i64> {
+        let (rx, ry) = (kernel.width() as isize / 2, kernel.height() as isize / 2);
+        let mut out = grid.clone();
+        for ((x, y), _) in grid.iter() {
+            let mut sum = 0;
+            for dy in -ry..=ry {
+                for dx in -rx..=rx {
+                    let weight = kernel[((dx + rx) as usize, (dy + ry) as usize)];
+                    let (sx, sy) = (x as isize + dx, y as isize + dy);
+                    let (w, h) = (grid.width() as isize, grid.height() as isize);
+                    let (sx, sy) = match border {
+                        Border::Zero if sx < 0 || sy < 0 || sx >= w || sy >= h => continue,
+                        Border::Zero => (sx, sy),
+                        Border::Clamp => (sx.clamp(0, w - 1), sy.clamp(0, h - 1)),
+                        Border::Wrap => (sx.rem_euclid(w), sy.rem_euclid(h)),
+                    };
+                    sum += weight * grid[(sx as usize, sy as usize)];
+                }
+            }
+            out[(x, y)] = sum;
+        }
+        out
+    }
+
+    fn grid(max_side: usize) -> impl Strategy<Value = Grid<i64>> {
+        (1..=max_side, 1..=max_side).prop_flat_map(|(width, height)| {
+            prop::collection::vec(-9..=9i64, width * height)
+                .prop_map(move |cells| Grid::from_cells(width, cells))
+        })
+    }
+
+    fn kernel() -> impl Strategy<Value = Grid<i64>> {
+        (0..=2usize, 0..=2usize).prop_flat_map(|(rx, ry)| {
+            let (width, height) = (2 * rx + 1, 2 * ry + 1);
+            prop::collection::vec(-3..=3i64, width * height)
+                .prop_map(move |weights| Grid::from_cells(width, weights))
+        })
+    }
+
+    fn border() -> impl Strategy<Value = Border> {
+        prop::sample::select(vec![Border::Zero, Border::Clamp, Border::Wrap])
+    }
+
+    #[test]
+    fn test_box_filter() {
+        let grid = Grid::from_cells(3, vec![1, 0, 1, 0, 1, 0]);
+        assert_eq!(
+            Grid::from_cells(3, vec![2, 3, 2, 2, 3, 2]),
+            box_filter(&grid, 1, Border::Zero)
+        );
+        // with 2 rows the window wraps onto the other row twice
+        assert_eq!(
+            Grid::from_cells(3, vec![4, 4, 4, 5, 5, 5]),
+            box_filter(&grid, 1, Border::Wrap)
+        );
+        assert_eq!(
+            Grid::from_cells(3, vec![5, 5, 5, 4, 4, 4]),
+            box_filter(&grid, 1, Border::Clamp)
+        );
+    }
+
+    #[test]
+    fn test_summed_area_table() {
+        let table = SummedAreaTable::new(&Grid::from_cells(3, vec![1, 2, 3, 4, 5, 6]));
+        assert_eq!(21, table.sum(0..3, 0..2));
+        assert_eq!(11, table.sum(1..3, 1..2));
+        assert_eq!(0, table.sum(2..2, 0..2));
+        assert_eq!(21, table.sum(0..10, 0..10));
+        assert_eq!(12, table.window(0, 0, 1));
+    }
+
+    proptest! {
+        #[test]
+        fn test_box_filter_against_naive(grid in grid(8), radius in 0..4usize, border in border()) {
+            let side = 2 * radius + 1;
+            let kernel = Grid::filled(side, side, 1);
+            prop_assert_eq!(naive(&grid, &kernel, border), box_filter(&grid, radius, border));
+        }
+
+        #[test]
+        fn test_convolve_against_naive(grid in grid(8), kernel in kernel(), border in border()) {
+            prop_assert_eq!(naive(&grid, &kernel, border), convolve(&grid, &kernel, border));
+        }
+
+        #[test]
+        fn test_summed_area_table_against_naive(grid in grid(8), radius in 0..4usize) {
+            let side = 2 * radius + 1;
+            let expected = naive(&grid, &Grid::filled(side, side, 1), Border::Zero);
+            let table = SummedAreaTable::new(&grid);
+            for ((x, y), &sum) in expected.iter() {
+                prop_assert_eq!(sum, table.window(x, y, radius));
+            }
+        }
+    }
+}
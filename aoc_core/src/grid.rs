@@ -0,0 +1,304 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::{ParseError, lines};
+
+/// Rectangular map of cells stored row by row, indexed by `(x, y)` with `y` going down.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` columns from its cells row by row.
+    ///
+    /// # Panics
+    ///
+    /// If the cells don't fill the last row.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't fill rows of {width}",
+            cells.len()
+        );
+        let height = cells.len() / width;
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Grid of `width` by `height` cells, `cell` gives each of them from its position.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| cell(x, y))
+            .collect();
+        Grid::from_cells(width, cells)
+    }
+
+    /// Parses a map with a character per cell, `cell` maps each of them and rejects the
+    /// invalid ones with `message`.
+    pub fn parse(
+        input: &str,
+        cell: impl Fn(char) -> Option<T>,
+        message: impl Display,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        for line in lines(input) {
+            let row_start = cells.len();
+            for (pos, c) in line.text.char_indices() {
+                let Some(value) = cell(c) else {
+                    return Err(line.error(&line.text[pos..pos + c.len_utf8()], &message));
+                };
+                cells.push(value);
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                None if row_width == 0 => return Err(line.error("", "the first row is empty")),
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    let message = format!("expected a row of {width} cells");
+                    return Err(line.error(line.text, message));
+                }
+                Some(_) => {}
+            }
+        }
+
+        match width {
+            Some(width) => Ok(Grid::from_cells(width, cells)),
+            None => Err(ParseError::new("the grid is empty")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Positions and cells row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, cell)| ((idx % width, idx / width), cell))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator {
+        self.cells.chunks_exact_mut(self.width)
+    }
+
+    /// Cells of column `x` from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} outside a grid of width {}",
+            self.width
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Positions of the up to 4 cells sharing a side with `(x, y)`.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.neighbors(x, y, &[(0, -1), (-1, 0), (1, 0), (0, 1)])
+    }
+
+    /// Positions of the up to 8 cells sharing a side or a corner with `(x, y)`.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.neighbors(
+            x,
+            y,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        )
+    }
+
+    fn neighbors(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx).filter(|&x| x < width)?;
+            let y = y.checked_add_signed(dy).filter(|&y| y < height)?;
+            Some((x, y))
+        })
+    }
+
+    /// The map back in characters, a row per line, `cell` giving the character of every
+    /// cell like the reverse of the one of `parse`.
+    pub fn display(&self, cell: impl Fn(&T) -> char) -> impl Display {
+        struct Shown<'a, T, F> {
+            grid: &'a Grid<T>,
+            cell: F,
+        }
+
+        impl<T, F: Fn(&T) -> char> Display for Shown<'_, T, F> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                for row in self.grid.rows() {
+                    let line: String = row.iter().map(&self.cell).collect();
+                    writeln!(f, "{line}")?;
+                }
+                Ok(())
+            }
+        }
+
+        Shown { grid: self, cell }
+    }
+
+    /// Grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid::from_cells(width, vec![value; width * height])
+    }
+
+    /// Grid mirrored along its diagonal, rows become columns.
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid::from_cells(self.height, cells)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) outside a {width}x{height} grid"))
+    }
+}
+
+/// The map back in characters, a row per line.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10), "expected a digit")
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(6, grid[(2, 1)]);
+        assert_eq!("123\n456\n", grid.to_string());
+        let odd = grid.display(|&digit| if digit % 2 == 1 { '#' } else { '.' });
+        assert_eq!("#.#\n.#.\n", odd.to_string());
+
+        let err = digits("123\n4x6\n").unwrap_err();
+        assert_eq!(Some(2), err.location.map(|location| location.line));
+        assert_eq!("expected a digit", err.message);
+        let err = digits("123\n45\n").unwrap_err();
+        assert_eq!("expected a row of 3 cells", err.message);
+        assert!(digits("\n").is_err());
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = digits("12\n34\n").unwrap();
+        assert_eq!(Some(&3), grid.get(0, 1));
+        assert_eq!(None, grid.get(2, 0));
+        assert_eq!(None, grid.get(0, 2));
+        grid[(1, 0)] = 9;
+        assert_eq!("19\n34\n", grid.to_string());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(
+            vec![(1, 0), (0, 1)],
+            grid.neighbors4(0, 0).collect::<Vec<_>>()
+        );
+        assert_eq!(4, grid.neighbors4(1, 1).count());
+        assert_eq!(
+            vec![(1, 0), (0, 1), (1, 1)],
+            grid.neighbors8(0, 0).collect::<Vec<_>>()
+        );
+        assert_eq!(8, grid.neighbors8(1, 1).count());
+        assert_eq!(5, grid.neighbors8(2, 1).count());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!(
+            vec![&[1, 2, 3][..], &[4, 5, 6]],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(&[4, 5, 6], grid.row(1));
+        assert_eq!(vec![&2, &5], grid.column(1).collect::<Vec<_>>());
+        assert_eq!("14\n25\n36\n", grid.transpose().to_string());
+        assert_eq!(
+            Some(((2, 1), &6)),
+            grid.iter().find(|&(_, &cell)| cell == 6)
+        );
+    }
+}
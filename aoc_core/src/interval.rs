@@ -0,0 +1,339 @@
+use std::ops::RangeInclusive;
+
+/// Integers with a next and a previous value, the values of an `IntervalSet`.
+pub trait Discrete: Copy + Ord {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn next(self) -> Option<Self>;
+    fn prev(self) -> Option<Self>;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(impl Discrete for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn prev(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+        })*
+    };
+}
+
+impl_discrete!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Set of integers stored as inclusive ranges, sorted and merged as soon as they overlap
+/// or touch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    /// `(start, end)` with a gap of at least one value between each other.
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// Merges sorted ranges that overlap or touch.
+    fn from_sorted(ranges: impl IntoIterator<Item = (T, T)>) -> Self {
+        let mut merged: Vec<(T, T)> = Vec::new();
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some((_, last_end)) if last_end.next().is_none_or(|next| next >= start) => {
+                    *last_end = (*last_end).max(end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+        IntervalSet { ranges: merged }
+    }
+
+    /// Adds the values of `range`, an empty range adds nothing.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // ranges overlapping or touching the new one are merged into it
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.next().is_some_and(|next| next < start));
+        let last = self
+            .ranges
+            .partition_point(|&(s, _)| end.next().is_none_or(|next| s <= next));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    /// Takes out the values of `range`, splitting the range around them if needed.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|&(_, e)| e < start);
+        let last = self.ranges.partition_point(|&(s, _)| s <= end);
+        if first == last {
+            return;
+        }
+        let (first_start, last_end) = (self.ranges[first].0, self.ranges[last - 1].1);
+        let before = start.prev().filter(|&prev| first_start <= prev);
+        let after = end.next().filter(|&next| next <= last_end);
+        let kept = before.map(|prev| (first_start, prev));
+        let kept = kept.into_iter().chain(after.map(|next| (next, last_end)));
+        self.ranges.splice(first..last, kept);
+    }
+
+    /// Binary search of the range holding `value`.
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges
+            .get(idx)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// Number of values in the set, `None` if `T` can't hold it, e.g. for every `u64`.
+    pub fn len(&self) -> Option<T> {
+        self.ranges.iter().try_fold(T::ZERO, |len, &(start, end)| {
+            len.checked_add(end.checked_sub(start)?.next()?)
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Ranges of the set in increasing order, neither overlapping nor touching.
+    pub fn ranges(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// Values left out between the first and the last range, a range per gap.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.windows(2).map(|pair| {
+            let ((_, end), (start, _)) = (pair[0], pair[1]);
+            // ranges never touch, so both ends of a gap exist
+            end.next().unwrap()..=start.prev().unwrap()
+        })
+    }
+
+    /// Values of `bounds` left out of the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        let (start, end) = bounds.into_inner();
+        let mut ranges = Vec::new();
+        let mut uncovered = Some(start);
+        for &(s, e) in &self.ranges {
+            let Some(from) = uncovered.filter(|&from| from <= end) else {
+                break;
+            };
+            if e < from {
+                continue;
+            }
+            if from < s {
+                ranges.push((from, s.prev().unwrap().min(end)));
+            }
+            uncovered = e.next();
+        }
+        if let Some(from) = uncovered.filter(|&from| from <= end) {
+            ranges.push((from, end));
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + other.ranges.len());
+        let (mut left, mut right) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+            let next = if l.0 <= r.0 {
+                left.next()
+            } else {
+                right.next()
+            };
+            ranges.extend(next);
+        }
+        ranges.extend(left.chain(right));
+        IntervalSet::from_sorted(ranges)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (self.ranges.iter(), other.ranges.iter());
+        let (mut l, mut r) = (left.next(), right.next());
+        while let (Some(&(ls, le)), Some(&(rs, re))) = (l, r) {
+            let (start, end) = (ls.max(rs), le.min(re));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // the range ending first can't overlap the later ranges of the other set
+            if le <= re {
+                l = left.next();
+            } else {
+                r = right.next();
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Values of the set left out of `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(&(start, _)), Some(&(_, end))) => {
+                self.intersection(&other.complement(start..=end))
+            }
+            _ => IntervalSet::new(),
+        }
+    }
+}
+
+/// Set of the values of any of the ranges.
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut ranges: Vec<(T, T)> = iter
+            .into_iter()
+            .map(RangeInclusive::into_inner)
+            .filter(|(start, end)| start <= end)
+            .collect();
+        ranges.sort_unstable_by_key(|&(start, _)| start);
+        IntervalSet::from_sorted(ranges)
+    }
+}
+
+impl<T: Discrete> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    fn set(ranges: &[RangeInclusive<u8>]) -> IntervalSet<u8> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn values(set: &IntervalSet<u8>) -> BTreeSet<u8> {
+        set.ranges().flatten().collect()
+    }
+
+    fn ranges() -> impl Strategy<Value = Vec<RangeInclusive<u8>>> {
+        prop::collection::vec((any::<u8>(), 0..40u8), 0..8).prop_map(|ranges| {
+            ranges
+                .into_iter()
+                .map(|(start, len)| start..=start.saturating_add(len))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut set = set(&[3..=5, 10..=14, 16..=20, 12..=18]);
+        assert_eq!(vec![3..=5, 10..=20], set.ranges().collect::<Vec<_>>());
+        set.insert(6..=8);
+        assert_eq!(vec![3..=8, 10..=20], set.ranges().collect::<Vec<_>>());
+        set.insert(0..=255);
+        assert_eq!(vec![0..=255], set.ranges().collect::<Vec<_>>());
+        #[allow(clippy::reversed_empty_ranges)]
+        set.insert(9..=1);
+        assert_eq!(1, set.ranges().count());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = set(&[0..=255]);
+        set.remove(10..=19);
+        set.remove(250..=255);
+        assert_eq!(vec![0..=9, 20..=249], set.ranges().collect::<Vec<_>>());
+        assert_eq!(vec![10..=19], set.gaps().collect::<Vec<_>>());
+        assert!(set.contains(9) && !set.contains(10) && !set.contains(255));
+        assert_eq!(Some(240), set.len());
+    }
+
+    #[test]
+    fn test_len() {
+        // one value more than `u8` holds
+        assert_eq!(None, set(&[0..=255]).len());
+        assert_eq!(Some(u8::MAX), set(&[0..=254]).len());
+        assert_eq!(Some(0), IntervalSet::<i8>::new().len());
+        assert_eq!(None, IntervalSet::from_iter([-128i8..=127]).len());
+    }
+
+    #[test]
+    fn test_complement() {
+        let set = set(&[3..=5, 10..=20]);
+        assert_eq!(
+            vec![0..=2, 6..=9, 21..=255],
+            set.complement(0..=255).ranges().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![6..=9],
+            set.complement(4..=12).ranges().collect::<Vec<_>>()
+        );
+        assert!(set.complement(11..=12).is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn test_against_btree_set(left in ranges(), right in ranges(), value in any::<u8>()) {
+            let (left, right) = (set(&left), set(&right));
+            let (l, r) = (values(&left), values(&right));
+
+            prop_assert_eq!(l.contains(&value), left.contains(value));
+            prop_assert_eq!(u8::try_from(l.len()).ok(), left.len());
+            prop_assert_eq!(&l | &r, values(&left.union(&right)));
+            prop_assert_eq!(&l & &r, values(&left.intersection(&right)));
+            prop_assert_eq!(&l - &r, values(&left.difference(&right)));
+            let all: BTreeSet<u8> = (0..=255).collect();
+            prop_assert_eq!(&all - &l, values(&left.complement(0..=255)));
+
+            let mut removed = left.clone();
+            removed.extend(right.ranges());
+            prop_assert_eq!(&l | &r, values(&removed));
+            right.ranges().for_each(|range| removed.remove(range));
+            prop_assert_eq!(&l - &r, values(&removed));
+
+            // ranges stay sorted with gaps between them
+            let ranges: Vec<_> = left.union(&right).ranges().collect();
+            for pair in ranges.windows(2) {
+                prop_assert!(pair[0].end().saturating_add(1) < *pair[1].start());
+            }
+        }
+    }
+}
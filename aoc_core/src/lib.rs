@@ -0,0 +1,66 @@
+mod filter;
+mod grid;
+mod interval;
+mod params;
+mod parse;
+mod union_find;
+
+use std::fmt::{self, Display};
+
+pub use filter::{Border, Number, SummedAreaTable, box_filter, convolve};
+pub use grid::Grid;
+pub use interval::{Discrete, IntervalSet};
+pub use params::{Overrides, Params, param_value, unknown_param};
+pub use parse::{Line, Location, ParseError, lines, read_input};
+pub use union_find::UnionFind;
+
+/// Answer of a part that may have none, e.g. a total overflowing its type. Shown as the
+/// value, or `none` which never matches a registered answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Answer<T>(pub Option<T>);
+
+impl<T: Display> Display for Answer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(value) => value.fmt(f),
+            None => write!(f, "none"),
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Answer<T> {
+    fn from(value: Option<T>) -> Self {
+        Answer(value)
+    }
+}
+
+/// A day's puzzle. The input is parsed once and both parts are solved from the parsed input,
+/// so tooling like the runner can treat every day the same way.
+pub trait Solution {
+    type Input;
+    /// Tunable constants of the puzzle, `()` for days without any.
+    type Params: Params;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Output1;
+    fn part2(input: &Self::Input) -> Self::Output2;
+
+    /// Parses with parameters other than the defaults. Days with parameters implement `parse`
+    /// as `parse_with` the defaults.
+    fn parse_with(input: &str, params: &Self::Params) -> Result<Self::Input, ParseError> {
+        let _ = params;
+        Self::parse(input)
+    }
+
+    /// Reads and parses `file_path`, errors point into the file.
+    fn parse_file(file_path: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_file_with(file_path, &Self::Params::default())
+    }
+
+    fn parse_file_with(file_path: &str, params: &Self::Params) -> Result<Self::Input, ParseError> {
+        let input = read_input(file_path)?;
+        Self::parse_with(&input, params).map_err(|err| err.in_file(file_path))
+    }
+}
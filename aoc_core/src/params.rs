@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// Parameter values by name, as given on the command line or in the answer registry.
+pub type Overrides = BTreeMap<String, String>;
+
+/// Tunable constants of a puzzle, defaulting to the values of the puzzle input.
+pub trait Params: Default {
+    /// Sets the parameter `name` from its text value.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParseError>;
+
+    /// The defaults with `overrides` applied.
+    fn with(overrides: &Overrides) -> Result<Self, ParseError> {
+        let mut params = Self::default();
+        for (name, value) in overrides {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+}
+
+/// Days without parameters.
+impl Params for () {
+    fn set(&mut self, name: &str, _value: &str) -> Result<(), ParseError> {
+        Err(unknown_param(name))
+    }
+}
+
+pub fn unknown_param(name: &str) -> ParseError {
+    ParseError::new(format!("unknown parameter `{name}`"))
+}
+
+/// Parses the value of the parameter `name`.
+pub fn param_value<T>(name: &str, value: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|err| ParseError::new(format!("invalid value `{value}` for `{name}`: {err}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Dial {
+        size: u32,
+    }
+
+    impl Params for Dial {
+        fn set(&mut self, name: &str, value: &str) -> Result<(), ParseError> {
+            match name {
+                "size" => self.size = param_value(name, value)?,
+                _ => return Err(unknown_param(name)),
+            }
+            Ok(())
+        }
+    }
+
+    fn overrides(name: &str, value: &str) -> Overrides {
+        Overrides::from([(name.to_string(), value.to_string())])
+    }
+
+    #[test]
+    fn test_with() {
+        assert_eq!(Dial { size: 0 }, Dial::with(&Overrides::new()).unwrap());
+        assert_eq!(
+            Dial { size: 7 },
+            Dial::with(&overrides("size", "7")).unwrap()
+        );
+        assert_eq!(
+            "invalid value `x` for `size`: invalid digit found in string",
+            Dial::with(&overrides("size", "x")).unwrap_err().message
+        );
+        assert_eq!(
+            "unknown parameter `start`",
+            Dial::with(&overrides("start", "7")).unwrap_err().message
+        );
+        assert!(<()>::with(&overrides("size", "7")).is_err());
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Error of a day's parser, pointing at the offending token when there is one.
+#[derive(Debug)]
+pub struct ParseError {
+    /// File the input was read from.
+    pub file: Option<PathBuf>,
+    pub location: Option<Location>,
+    pub message: String,
+}
+
+/// Position of the offending token in the input.
+#[derive(Debug, PartialEq)]
+pub struct Location {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub token: String,
+    /// The whole line, to render the error.
+    pub line_text: String,
+}
+
+impl ParseError {
+    /// Error about the input as a whole, e.g. a missing section.
+    pub fn new(message: impl Display) -> Self {
+        ParseError {
+            file: None,
+            location: None,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn in_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self
+            .file
+            .as_deref()
+            .map_or("<input>".into(), Path::to_string_lossy);
+
+        let Some(location) = &self.location else {
+            return write!(f, "{file}: {}", self.message);
+        };
+
+        // rustc-like rendering with the offending token underlined
+        let Location {
+            line,
+            column,
+            token,
+            line_text,
+        } = location;
+        let gutter = " ".repeat(line.to_string().len());
+        writeln!(f, "{file}:{line}:{column}: {}", self.message)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line} | {line_text}")?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(column - 1),
+            "^".repeat(token.chars().count().max(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of the input with its 1-based line number.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Error pointing at `token`, which should be a slice of this line. Any other token is
+    /// reported at the end of the line, e.g. a missing value.
+    pub fn error(&self, token: &str, message: impl Display) -> ParseError {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|&offset| offset + token.len() <= self.text.len())
+            .unwrap_or(self.text.len());
+        let column = self.text[..offset].chars().count() + 1;
+
+        ParseError {
+            file: None,
+            location: Some(Location {
+                line: self.number,
+                column,
+                token: token.to_string(),
+                line_text: self.text.to_string(),
+            }),
+            message: message.to_string(),
+        }
+    }
+
+    /// Parses `token`, a slice of this line, reporting failures at its position.
+    pub fn parse<T>(&self, token: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        token
+            .parse()
+            .map_err(|err| self.error(token, format!("invalid value `{token}`: {err}")))
+    }
+
+    /// Fails at the first character rejected by `valid`.
+    pub fn check_chars(
+        &self,
+        valid: impl Fn(char) -> bool,
+        message: impl Display,
+    ) -> Result<(), ParseError> {
+        match self.text.char_indices().find(|&(_, c)| !valid(c)) {
+            Some((pos, c)) => Err(self.error(&self.text[pos..pos + c.len_utf8()], message)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Numbered lines of the input, ignoring trailing blank lines.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    let input = input.trim_end_matches(['\n', '\r']);
+    input.lines().enumerate().map(|(idx, text)| Line {
+        number: idx + 1,
+        text,
+    })
+}
+
+/// Content of `file_path`, a failure to read it is reported as an error of the file.
+pub fn read_input(file_path: &str) -> Result<String, ParseError> {
+    std::fs::read_to_string(file_path)
+        .map_err(|err| ParseError::new(format!("cannot read file: {err}")).in_file(file_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_location() {
+        let line = lines("1,2\n3,x4").nth(1).unwrap();
+        let (_, token) = line.text.split_once(',').unwrap();
+        let err = line.parse::<u32>(token).unwrap_err();
+
+        assert_eq!(
+            Some(Location {
+                line: 2,
+                column: 3,
+                token: "x4".to_string(),
+                line_text: "3,x4".to_string(),
+            }),
+            err.location
+        );
+        assert_eq!(
+            "input:2:3: invalid value `x4`: invalid digit found in string\n  |\n2 | 3,x4\n  |   ^^",
+            err.in_file("input").to_string()
+        );
+    }
+
+    #[test]
+    fn test_trailing_blank_lines() {
+        let texts: Vec<_> = lines("1\n\n2\n\n\r\n").map(|line| line.text).collect();
+        assert_eq!(vec!["1", "", "2"], texts);
+    }
+
+    #[test]
+    fn test_missing_token() {
+        let line = lines("1,").next().unwrap();
+        let err = line.error("", "missing value");
+
+        assert_eq!(3, err.location.unwrap().column);
+    }
+
+    #[test]
+    fn test_check_chars() {
+        let line = lines("..#.x").next().unwrap();
+
+        assert!(line.check_chars(|c| c != 'y', "").is_ok());
+        let err = line
+            .check_chars(|c| c == '.' || c == '#', "bad cell")
+            .unwrap_err();
+        assert_eq!(5, err.location.unwrap().column);
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let err = read_input("does_not_exist").unwrap_err();
+
+        assert!(err.location.is_none());
+        assert!(
+            err.to_string()
+                .starts_with("does_not_exist: cannot read file")
+        );
+    }
+}
@@ -0,0 +1,131 @@
+/// Disjoint sets of the elements `0..len`, merged by `union`. Finding a set compresses the
+/// path to its root and the smaller set is always merged into the larger one, so both
+/// are close to constant time.
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    /// Parent of every element, a root is its own parent.
+    parents: Vec<usize>,
+    /// Size of the set of every root, stale for the other elements.
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// `len` elements, each in a set of its own.
+    pub fn new(len: usize) -> Self {
+        UnionFind {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Root of the set holding `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut element = element;
+        while element != root {
+            element = std::mem::replace(&mut self.parents[element], root);
+        }
+        root
+    }
+
+    /// Merges the sets of `a` and `b`, returns whether they were apart.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (large, small) = if self.sizes[a] < self.sizes[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Size of the set holding `element`.
+    pub fn size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// Number of disjoint sets.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Size of every set, in no particular order.
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter(|&(element, &parent)| element == parent)
+            .map(|(root, _)| self.sizes[root])
+    }
+
+    /// Sizes of the `k` largest sets, largest first.
+    pub fn largest(&self, k: usize) -> Vec<usize> {
+        let mut sizes: Vec<usize> = self.component_sizes().collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.truncate(k);
+        sizes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(6, sets.components());
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+
+        assert_eq!(3, sets.components());
+        assert_eq!(4, sets.size(2));
+        assert_eq!(1, sets.size(5));
+        assert_eq!(6, sets.component_sizes().sum::<usize>());
+        assert_eq!(vec![4, 1], sets.largest(2));
+        assert_eq!(vec![4, 1, 1], sets.largest(10));
+    }
+
+    #[test]
+    fn test_path_compression() {
+        let mut sets = UnionFind::new(100);
+        for element in 1..100 {
+            sets.union(element - 1, element);
+        }
+        let root = sets.find(99);
+        assert!((0..100).all(|element| sets.find(element) == root));
+        assert!(sets.parents.iter().all(|&parent| parent == root));
+        assert_eq!(vec![100], sets.largest(3));
+    }
+}
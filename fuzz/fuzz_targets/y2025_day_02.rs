@@ -0,0 +1,16 @@
+#![no_main]
+
+use aoc_core::Solution;
+use libfuzzer_sys::fuzz_target;
+use y2025_day_02::Day02;
+
+fuzz_target!(|input: &str| {
+    // the parts check every ID of the ranges, huge ranges only time out
+    let ids = Day02::parse(input).map(|ids| {
+        ids.iter()
+            .fold(0u64, |len, &(start, end)| len.saturating_add(end - start))
+    });
+    if ids.is_ok_and(|ids| ids < 1_000_000) {
+        aoc_fuzz::solve::<Day02>(input);
+    }
+});
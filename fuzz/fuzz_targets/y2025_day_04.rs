@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use y2025_day_04::Day04;
+
+fuzz_target!(|input: &str| aoc_fuzz::solve::<Day04>(input));
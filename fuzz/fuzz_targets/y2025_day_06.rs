@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use y2025_day_06::Day06;
+
+fuzz_target!(|input: &str| aoc_fuzz::solve::<Day06>(input));
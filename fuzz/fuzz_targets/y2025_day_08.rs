@@ -0,0 +1,7 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use y2025_day_08::{Day08, Day08Params};
+
+// the default number of pairs needs far more boxes than a fuzzed input has
+fuzz_target!(|input: &str| aoc_fuzz::solve_with::<Day08>(input, &Day08Params::EXAMPLE));
@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use y2025_day_10::Day10;
+
+fuzz_target!(|input: &str| aoc_fuzz::solve::<Day10>(input));
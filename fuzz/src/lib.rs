@@ -0,0 +1,14 @@
+use aoc_core::Solution;
+
+/// Solves both parts of the input if it parses, the parts rely on what the parser checks.
+pub fn solve_with<S: Solution>(input: &str, params: &S::Params) {
+    if let Ok(input) = S::parse_with(input, params) {
+        S::part1(&input);
+        S::part2(&input);
+    }
+}
+
+/// Solves both parts of the input with the default parameters if it parses.
+pub fn solve<S: Solution>(input: &str) {
+    solve_with::<S>(input, &S::Params::default());
+}